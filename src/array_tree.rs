@@ -0,0 +1,538 @@
+use crate::slice_tree::traverse::{
+    InOrder, InOrderIndexed, InOrderIndexedMut, InOrderMut, LevelOrderIndexed,
+    LevelOrderIndexedMut, PostOrder, PostOrderIndexed, PostOrderIndexedMut, PostOrderMut, PreOrder,
+    PreOrderIndexed, PreOrderIndexedMut, PreOrderMut,
+};
+use crate::slice_tree::{Ancestors, IterLevels, IterLevelsMut, Subtree};
+use crate::{CompleteBinaryTree, CompleteTree, Index, SliceTree};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut, RangeBounds};
+use core::ptr;
+use core::slice::{self, Iter, IterMut};
+
+pub struct ArrayTree<const N: usize, T, const CAP: usize> {
+    nodes: [MaybeUninit<T>; CAP],
+    len: usize,
+}
+
+impl<const N: usize, T, const CAP: usize> ArrayTree<N, T, CAP> {
+    pub const fn new() -> Self {
+        let nodes = [const { MaybeUninit::uninit() }; CAP];
+        let len = 0;
+        Self { nodes, len }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    pub const fn remaining_capacity(&self) -> usize {
+        CAP - self.len
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    pub fn push(&mut self, node: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(node);
+        }
+        self.nodes[self.len].write(node);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let node = unsafe { self.nodes[self.len].assume_init_read() };
+        Some(node)
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let tail = ptr::slice_from_raw_parts_mut(
+            unsafe { self.nodes.as_mut_ptr().add(len) }.cast::<T>(),
+            self.len - len,
+        );
+        self.len = len;
+        unsafe { ptr::drop_in_place(tail) };
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub fn as_slice_tree(&self) -> &SliceTree<N, T> {
+        let nodes = unsafe { slice::from_raw_parts(self.nodes.as_ptr().cast::<T>(), self.len) };
+        nodes.into()
+    }
+
+    pub fn as_mut_slice_tree(&mut self) -> &mut SliceTree<N, T> {
+        let nodes =
+            unsafe { slice::from_raw_parts_mut(self.nodes.as_mut_ptr().cast::<T>(), self.len) };
+        nodes.into()
+    }
+}
+
+impl<const N: usize, T, const CAP: usize> Drop for ArrayTree<N, T, CAP> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<const N: usize, T, const CAP: usize> Default for ArrayTree<N, T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T: Clone, const CAP: usize> Clone for ArrayTree<N, T, CAP> {
+    fn clone(&self) -> Self {
+        let mut tree = Self::new();
+        for node in self.as_slice_tree().iter() {
+            tree.nodes[tree.len].write(node.clone());
+            tree.len += 1;
+        }
+        tree
+    }
+}
+
+impl<const N: usize, T: fmt::Debug, const CAP: usize> fmt::Debug for ArrayTree<N, T, CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ArrayTree")
+            .field(&self.as_slice_tree())
+            .finish()
+    }
+}
+
+impl<const N: usize, T: PartialEq, const CAP: usize> PartialEq for ArrayTree<N, T, CAP> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice_tree() == other.as_slice_tree()
+    }
+}
+
+impl<const N: usize, T: Eq, const CAP: usize> Eq for ArrayTree<N, T, CAP> {}
+
+impl<const N: usize, T: PartialOrd, const CAP: usize> PartialOrd for ArrayTree<N, T, CAP> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_slice_tree().partial_cmp(other.as_slice_tree())
+    }
+}
+
+impl<const N: usize, T: Ord, const CAP: usize> Ord for ArrayTree<N, T, CAP> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice_tree().cmp(other.as_slice_tree())
+    }
+}
+
+impl<const N: usize, T: Hash, const CAP: usize> Hash for ArrayTree<N, T, CAP> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice_tree().hash(state);
+    }
+}
+
+impl<const N: usize, T, const CAP: usize> Deref for ArrayTree<N, T, CAP> {
+    type Target = SliceTree<N, T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice_tree()
+    }
+}
+
+impl<const N: usize, T, const CAP: usize> DerefMut for ArrayTree<N, T, CAP> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice_tree()
+    }
+}
+
+impl<const N: usize, T, const CAP: usize> AsRef<[T]> for ArrayTree<N, T, CAP> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice_tree()
+    }
+}
+
+impl<const N: usize, T, const CAP: usize> AsMut<[T]> for ArrayTree<N, T, CAP> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice_tree()
+    }
+}
+
+impl<const N: usize, T, const CAP: usize> AsRef<SliceTree<N, T>> for ArrayTree<N, T, CAP> {
+    fn as_ref(&self) -> &SliceTree<N, T> {
+        self.as_slice_tree()
+    }
+}
+
+impl<const N: usize, T, const CAP: usize> AsMut<SliceTree<N, T>> for ArrayTree<N, T, CAP> {
+    fn as_mut(&mut self) -> &mut SliceTree<N, T> {
+        self.as_mut_slice_tree()
+    }
+}
+
+impl<'a, const N: usize, T, const CAP: usize> IntoIterator for &'a ArrayTree<N, T, CAP> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice_tree().iter()
+    }
+}
+
+impl<'a, const N: usize, T, const CAP: usize> IntoIterator for &'a mut ArrayTree<N, T, CAP> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice_tree().iter_mut()
+    }
+}
+
+impl<const N: usize, T, const CAP: usize> CompleteTree<N> for ArrayTree<N, T, CAP> {
+    type Node = T;
+
+    type IterChildren<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterChildrenMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevel<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevelMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type LevelOrder<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type LevelOrderMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type PreOrder<'a>
+        = PreOrder<'a, N, T>
+    where
+        Self: 'a;
+
+    type PreOrderMut<'a>
+        = PreOrderMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrder<'a>
+        = PostOrder<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrderMut<'a>
+        = PostOrderMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexed<'a>
+        = LevelOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexedMut<'a>
+        = LevelOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type PreOrderIndexed<'a>
+        = PreOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type PreOrderIndexedMut<'a>
+        = PreOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrderIndexed<'a>
+        = PostOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrderIndexedMut<'a>
+        = PostOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type Ancestors<'a>
+        = Ancestors<'a, N, T>
+    where
+        Self: 'a;
+
+    type IterSiblings<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterSiblingsMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type Subtree<'a>
+        = Subtree<'a, Index<N>, T>
+    where
+        Self: 'a;
+
+    type IterLeaves<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterLeavesMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterInternal<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterInternalMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevels<'a>
+        = IterLevels<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevelsMut<'a>
+        = IterLevelsMut<'a, T>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn swap(&mut self, index_a: Index<N>, index_b: Index<N>) -> Option<()> {
+        CompleteTree::<N>::swap(self.as_mut_slice_tree(), index_a, index_b)
+    }
+
+    fn replace(&mut self, index: Index<N>, node: Self::Node) -> Option<Self::Node> {
+        CompleteTree::<N>::replace(self.as_mut_slice_tree(), index, node)
+    }
+
+    fn node(&self, index: Index<N>) -> Option<&Self::Node> {
+        CompleteTree::<N>::node(self.as_slice_tree(), index)
+    }
+
+    fn node_mut(&mut self, index: Index<N>) -> Option<&mut Self::Node> {
+        CompleteTree::<N>::node_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn root(&self) -> Option<&Self::Node> {
+        CompleteTree::<N>::root(self.as_slice_tree())
+    }
+
+    fn root_mut(&mut self) -> Option<&mut Self::Node> {
+        CompleteTree::<N>::root_mut(self.as_mut_slice_tree())
+    }
+
+    fn last(&self) -> Option<&Self::Node> {
+        CompleteTree::<N>::last(self.as_slice_tree())
+    }
+
+    fn last_mut(&mut self) -> Option<&mut Self::Node> {
+        CompleteTree::<N>::last_mut(self.as_mut_slice_tree())
+    }
+
+    fn ancestors(&self, index: Index<N>) -> Option<Self::Ancestors<'_>> {
+        CompleteTree::<N>::ancestors(self.as_slice_tree(), index)
+    }
+
+    fn iter_siblings(&self, index: Index<N>) -> Option<Self::IterSiblings<'_>> {
+        CompleteTree::<N>::iter_siblings(self.as_slice_tree(), index)
+    }
+
+    fn iter_siblings_mut(&mut self, index: Index<N>) -> Option<Self::IterSiblingsMut<'_>> {
+        CompleteTree::<N>::iter_siblings_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn iter_children(&self, index: Index<N>) -> Option<Self::IterChildren<'_>> {
+        CompleteTree::<N>::iter_children(self.as_slice_tree(), index)
+    }
+
+    fn iter_children_mut(&mut self, index: Index<N>) -> Option<Self::IterChildrenMut<'_>> {
+        CompleteTree::<N>::iter_children_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn subtree(&self, index: Index<N>) -> Option<Self::Subtree<'_>> {
+        CompleteTree::<N>::subtree(self.as_slice_tree(), index)
+    }
+
+    fn iter_level(&self, depth: usize) -> Option<Self::IterLevel<'_>> {
+        CompleteTree::<N>::iter_level(self.as_slice_tree(), depth)
+    }
+
+    fn iter_level_mut(&mut self, depth: usize) -> Option<Self::IterLevelMut<'_>> {
+        CompleteTree::<N>::iter_level_mut(self.as_mut_slice_tree(), depth)
+    }
+
+    fn iter_leaves(&self) -> Self::IterLeaves<'_> {
+        CompleteTree::<N>::iter_leaves(self.as_slice_tree())
+    }
+
+    fn iter_leaves_mut(&mut self) -> Self::IterLeavesMut<'_> {
+        CompleteTree::<N>::iter_leaves_mut(self.as_mut_slice_tree())
+    }
+
+    fn iter_internal(&self) -> Self::IterInternal<'_> {
+        CompleteTree::<N>::iter_internal(self.as_slice_tree())
+    }
+
+    fn iter_internal_mut(&mut self) -> Self::IterInternalMut<'_> {
+        CompleteTree::<N>::iter_internal_mut(self.as_mut_slice_tree())
+    }
+
+    fn iter_levels<R>(&self, depths: R) -> Self::IterLevels<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        CompleteTree::<N>::iter_levels(self.as_slice_tree(), depths)
+    }
+
+    fn iter_levels_mut<R>(&mut self, depths: R) -> Self::IterLevelsMut<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        CompleteTree::<N>::iter_levels_mut(self.as_mut_slice_tree(), depths)
+    }
+
+    fn traverse_level_order(&self) -> Self::LevelOrder<'_> {
+        CompleteTree::<N>::traverse_level_order(self.as_slice_tree())
+    }
+
+    fn traverse_level_order_mut(&mut self) -> Self::LevelOrderMut<'_> {
+        CompleteTree::<N>::traverse_level_order_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_pre_order(&self) -> Self::PreOrder<'_> {
+        CompleteTree::<N>::traverse_pre_order(self.as_slice_tree())
+    }
+
+    fn traverse_pre_order_mut(&mut self) -> Self::PreOrderMut<'_> {
+        CompleteTree::<N>::traverse_pre_order_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_post_order(&self) -> Self::PostOrder<'_> {
+        CompleteTree::<N>::traverse_post_order(self.as_slice_tree())
+    }
+
+    fn traverse_post_order_mut(&mut self) -> Self::PostOrderMut<'_> {
+        CompleteTree::<N>::traverse_post_order_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_pre_order_from(&self, index: Index<N>) -> Option<Self::PreOrder<'_>> {
+        CompleteTree::<N>::traverse_pre_order_from(self.as_slice_tree(), index)
+    }
+
+    fn traverse_pre_order_from_mut(&mut self, index: Index<N>) -> Option<Self::PreOrderMut<'_>> {
+        CompleteTree::<N>::traverse_pre_order_from_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn traverse_post_order_from(&self, index: Index<N>) -> Option<Self::PostOrder<'_>> {
+        CompleteTree::<N>::traverse_post_order_from(self.as_slice_tree(), index)
+    }
+
+    fn traverse_post_order_from_mut(&mut self, index: Index<N>) -> Option<Self::PostOrderMut<'_>> {
+        CompleteTree::<N>::traverse_post_order_from_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn traverse_level_order_indexed(&self) -> Self::LevelOrderIndexed<'_> {
+        CompleteTree::<N>::traverse_level_order_indexed(self.as_slice_tree())
+    }
+
+    fn traverse_level_order_indexed_mut(&mut self) -> Self::LevelOrderIndexedMut<'_> {
+        CompleteTree::<N>::traverse_level_order_indexed_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_pre_order_indexed(&self) -> Self::PreOrderIndexed<'_> {
+        CompleteTree::<N>::traverse_pre_order_indexed(self.as_slice_tree())
+    }
+
+    fn traverse_pre_order_indexed_mut(&mut self) -> Self::PreOrderIndexedMut<'_> {
+        CompleteTree::<N>::traverse_pre_order_indexed_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_post_order_indexed(&self) -> Self::PostOrderIndexed<'_> {
+        CompleteTree::<N>::traverse_post_order_indexed(self.as_slice_tree())
+    }
+
+    fn traverse_post_order_indexed_mut(&mut self) -> Self::PostOrderIndexedMut<'_> {
+        CompleteTree::<N>::traverse_post_order_indexed_mut(self.as_mut_slice_tree())
+    }
+}
+
+impl<T, const CAP: usize> CompleteBinaryTree for ArrayTree<2, T, CAP> {
+    type InOrder<'a>
+        = InOrder<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderMut<'a>
+        = InOrderMut<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderIndexed<'a>
+        = InOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderIndexedMut<'a>
+        = InOrderIndexedMut<'a, T>
+    where
+        Self: 'a;
+
+    fn traverse_in_order(&self) -> InOrder<'_, T> {
+        CompleteBinaryTree::traverse_in_order(self.as_slice_tree())
+    }
+
+    fn traverse_in_order_mut(&mut self) -> InOrderMut<'_, T> {
+        CompleteBinaryTree::traverse_in_order_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_in_order_from(&self, index: Index<2>) -> Option<Self::InOrder<'_>> {
+        CompleteBinaryTree::traverse_in_order_from(self.as_slice_tree(), index)
+    }
+
+    fn traverse_in_order_from_mut(&mut self, index: Index<2>) -> Option<Self::InOrderMut<'_>> {
+        CompleteBinaryTree::traverse_in_order_from_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn traverse_in_order_indexed(&self) -> Self::InOrderIndexed<'_> {
+        CompleteBinaryTree::traverse_in_order_indexed(self.as_slice_tree())
+    }
+
+    fn traverse_in_order_indexed_mut(&mut self) -> Self::InOrderIndexedMut<'_> {
+        CompleteBinaryTree::traverse_in_order_indexed_mut(self.as_mut_slice_tree())
+    }
+}
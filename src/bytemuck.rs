@@ -0,0 +1,24 @@
+use crate::SliceTree;
+use ::bytemuck::{Pod, PodCastError};
+
+impl<const N: usize, T: Pod> SliceTree<N, T> {
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<&Self, PodCastError> {
+        let nodes: &[T] = ::bytemuck::try_cast_slice(bytes)?;
+        Ok(nodes.into())
+    }
+
+    pub fn try_from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Self, PodCastError> {
+        let nodes: &mut [T] = ::bytemuck::try_cast_slice_mut(bytes)?;
+        Ok(nodes.into())
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        let nodes: &[T] = self.into();
+        ::bytemuck::cast_slice(nodes)
+    }
+
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        let nodes: &mut [T] = self.into();
+        ::bytemuck::cast_slice_mut(nodes)
+    }
+}
@@ -0,0 +1,302 @@
+use crate::index::traverse::{LevelOrder, PreOrder};
+use crate::{CompleteTree, Index, IndexRange};
+use core::fmt::{self, Write};
+
+pub type NodeFormatter<T> = fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layout {
+    #[default]
+    TopDown,
+    Sideways,
+}
+
+#[derive(Clone)]
+pub struct Display<'a, const N: usize, T: ?Sized, F> {
+    tree: &'a T,
+    formatter: F,
+    layout: Layout,
+    max_depth: Option<usize>,
+    max_width: Option<usize>,
+}
+
+impl<'a, const N: usize, T: ?Sized, F> Display<'a, N, T, F> {
+    pub(crate) fn new(tree: &'a T, formatter: F) -> Self {
+        Self {
+            tree,
+            formatter,
+            layout: Layout::default(),
+            max_depth: None,
+            max_width: None,
+        }
+    }
+
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Clips every line to `width` columns. A top-down layout that would not
+    /// fit falls back to the sideways layout, so the root is never clipped off.
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    pub fn formatter<G>(self, formatter: G) -> Display<'a, N, T, G> {
+        Display {
+            tree: self.tree,
+            formatter,
+            layout: self.layout,
+            max_depth: self.max_depth,
+            max_width: self.max_width,
+        }
+    }
+}
+
+impl<const N: usize, T, F> Display<'_, N, T, F>
+where
+    T: CompleteTree<N> + ?Sized,
+    F: Fn(&T::Node, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn depth(&self) -> usize {
+        let height = self.tree.height();
+        self.max_depth.map_or(height, |depth| depth.min(height))
+    }
+
+    fn node(&self, index: Index<N>) -> Node<'_, T::Node, F> {
+        let Some(node) = self.tree.node(index) else {
+            unreachable!()
+        };
+        Node(node, &self.formatter)
+    }
+
+    fn width(&self, index: Index<N>) -> usize {
+        let mut counter = Counter(0);
+        let _ = write!(counter, "{}", self.node(index));
+        counter.0
+    }
+
+    fn cell(&self, depth: usize) -> usize {
+        LevelOrder::<N>::new(self.tree.len())
+            .max_depth(depth)
+            .map(|index| self.width(index))
+            .max()
+            .unwrap_or(0)
+            + 1
+    }
+
+    fn fits_top_down(&self) -> bool {
+        let Some(max_width) = self.max_width else {
+            return true;
+        };
+        let depth = self.depth();
+        slot_width::<N>(0, depth, self.cell(depth)) - 1 <= max_width
+    }
+
+    fn fmt_top_down<W: Write>(&self, out: &mut Clip<W>) -> fmt::Result {
+        let len = self.tree.len();
+        let depth = self.depth();
+        let cell = self.cell(depth);
+
+        for level in 0..=depth {
+            if level > 0 {
+                out.write_char('\n')?;
+                self.fmt_connectors(out, level - 1, depth, cell)?;
+                out.write_char('\n')?;
+            }
+
+            let slot = slot_width::<N>(level, depth, cell);
+            let mut column = 0;
+            for index in IndexRange::<N>::level(level).cap(len) {
+                let width = self.width(index);
+                let center = center(index.offset(), slot);
+                let start = center - width.saturating_sub(1) / 2;
+                if out.is_clipped(start) {
+                    break;
+                }
+                write!(out, "{:1$}{2}", "", start - column, self.node(index))?;
+                column = start + width;
+            }
+        }
+        Ok(())
+    }
+
+    fn fmt_connectors<W: Write>(
+        &self,
+        out: &mut Clip<W>,
+        level: usize,
+        depth: usize,
+        cell: usize,
+    ) -> fmt::Result {
+        let len = self.tree.len();
+        let slot = slot_width::<N>(level, depth, cell);
+        let child_slot = slot_width::<N>(level + 1, depth, cell);
+        let mut column = 0;
+        for index in IndexRange::<N>::level(level).cap(len) {
+            let children = index.iter_children().cap(len).len();
+            if children == 0 {
+                break;
+            }
+
+            let parent = center(index.offset(), slot);
+            let first = center(index.offset().saturating_mul(N), child_slot);
+            let last = first.saturating_add((children - 1).saturating_mul(child_slot));
+            let start = parent.min(first);
+            let end = parent.max(last);
+            if out.is_clipped(start) {
+                break;
+            }
+
+            write!(out, "{:1$}", "", start - column)?;
+            for position in start..=end {
+                let up = position == parent;
+                let down = position >= first
+                    && position <= last
+                    && (position - first).is_multiple_of(child_slot);
+                let left = position > start;
+                let right = position < end;
+                out.write_char(connector(up, down, left, right))?;
+            }
+            column = end + 1;
+        }
+        Ok(())
+    }
+
+    fn fmt_sideways<W: Write>(&self, out: &mut Clip<W>) -> fmt::Result {
+        let len = self.tree.len();
+        let has_next_sibling = |index: Index<N>| {
+            index
+                .next_sibling()
+                .is_some_and(|sibling| sibling.to_flattened() < len)
+        };
+
+        let indices = PreOrder::<N>::new(len).max_depth(self.depth());
+        for (i, index) in indices.enumerate() {
+            if i > 0 {
+                out.write_char('\n')?;
+            }
+            for ancestor in index.ancestors().rev().skip(1) {
+                let prefix = if has_next_sibling(ancestor) {
+                    "│   "
+                } else {
+                    "    "
+                };
+                out.write_str(prefix)?;
+            }
+            if index.depth() > 0 {
+                let branch = if has_next_sibling(index) {
+                    "├── "
+                } else {
+                    "└── "
+                };
+                out.write_str(branch)?;
+            }
+            write!(out, "{}", self.node(index))?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize, T, F> fmt::Display for Display<'_, N, T, F>
+where
+    T: CompleteTree<N> + ?Sized,
+    F: Fn(&T::Node, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.tree.is_empty() {
+            return Ok(());
+        }
+        let mut out = Clip::new(f, self.max_width);
+        match self.layout {
+            Layout::TopDown if self.fits_top_down() => self.fmt_top_down(&mut out),
+            Layout::TopDown | Layout::Sideways => self.fmt_sideways(&mut out),
+        }
+    }
+}
+
+pub(crate) struct Node<'a, T, F>(pub(crate) &'a T, pub(crate) &'a F);
+
+impl<T, F> fmt::Display for Node<'_, T, F>
+where
+    F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.1)(self.0, f)
+    }
+}
+
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+struct Clip<W> {
+    inner: W,
+    column: usize,
+    max_width: Option<usize>,
+}
+
+impl<W: Write> Clip<W> {
+    fn new(inner: W, max_width: Option<usize>) -> Self {
+        Self {
+            inner,
+            column: 0,
+            max_width,
+        }
+    }
+
+    fn is_clipped(&self, column: usize) -> bool {
+        self.max_width.is_some_and(|max_width| column >= max_width)
+    }
+}
+
+impl<W: Write> Write for Clip<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if c == '\n' {
+                self.column = 0;
+            } else if self.is_clipped(self.column) {
+                continue;
+            } else {
+                self.column += 1;
+            }
+            self.inner.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+fn slot_width<const N: usize>(level: usize, depth: usize, cell: usize) -> usize {
+    let leaves = N.saturating_pow((depth - level) as u32);
+    cell.saturating_mul(leaves)
+}
+
+fn center(offset: usize, slot: usize) -> usize {
+    offset.saturating_mul(slot).saturating_add((slot - 1) / 2)
+}
+
+fn connector(up: bool, down: bool, left: bool, right: bool) -> char {
+    match (up, down, left, right) {
+        (false, false, _, _) => '─',
+        (true, false, true, true) => '┴',
+        (true, false, true, false) => '┘',
+        (true, false, false, _) => '└',
+        (false, true, true, true) => '┬',
+        (false, true, true, false) => '┐',
+        (false, true, false, _) => '┌',
+        (true, true, true, true) => '┼',
+        (true, true, true, false) => '┤',
+        (true, true, false, true) => '├',
+        (true, true, false, false) => '│',
+    }
+}
@@ -0,0 +1,334 @@
+pub use self::path::Ancestors;
+pub use self::subtree::SubtreeLevels;
+
+use crate::index::{TreeIndex, level_start, sealed, split_flattened};
+use crate::{Error, Index};
+use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
+
+mod path;
+mod subtree;
+pub mod traverse;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynIndex {
+    arity: usize,
+    depth: usize,
+    offset: usize,
+}
+
+impl DynIndex {
+    pub const fn arity(&self) -> usize {
+        self.arity
+    }
+
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn new(arity: usize, depth: usize, offset: usize) -> Option<Self> {
+        if arity == 0 {
+            return None;
+        }
+        if arity == 1 {
+            return (offset == 0).then_some(Self {
+                arity,
+                depth,
+                offset,
+            });
+        }
+
+        // `level_start` rejects every depth past the last representable level, so
+        // `depth` fits in `u32` from here on.
+        let start = level_start(arity, depth)?;
+        if let Some(width) = arity.checked_pow(depth as u32)
+            && offset >= width
+        {
+            return None;
+        }
+        start.checked_add(offset)?;
+        Some(Self {
+            arity,
+            depth,
+            offset,
+        })
+    }
+
+    pub fn root(arity: usize) -> Option<Self> {
+        Self::new(arity, 0, 0)
+    }
+
+    pub fn parent(&self) -> Option<Self> {
+        if self.depth == 0 {
+            return None;
+        }
+
+        let arity = self.arity;
+        let depth = self.depth - 1;
+        let offset = self.offset / self.arity;
+        Some(Self {
+            arity,
+            depth,
+            offset,
+        })
+    }
+
+    pub fn ancestors(&self) -> Ancestors {
+        Ancestors::new(*self)
+    }
+
+    pub fn ancestor_at_depth(&self, depth: usize) -> Option<Self> {
+        if depth > self.depth {
+            return None;
+        }
+
+        let arity = self.arity;
+        let height = self.depth - depth;
+        let offset = match arity.checked_pow(height as u32) {
+            Some(width) => self.offset / width,
+            None => 0,
+        };
+        Some(Self {
+            arity,
+            depth,
+            offset,
+        })
+    }
+
+    pub fn first_child(&self) -> Option<Self> {
+        self.child(0)
+    }
+
+    pub fn last_child(&self) -> Option<Self> {
+        self.child(self.arity - 1)
+    }
+
+    pub fn child(&self, n: usize) -> Option<Self> {
+        if n >= self.arity {
+            return None;
+        }
+        let depth = self.depth.checked_add(1)?;
+        let offset = self.offset.checked_mul(self.arity)?.checked_add(n)?;
+        Self::new(self.arity, depth, offset)
+    }
+
+    pub fn sibling_number(&self) -> usize {
+        if self.depth == 0 {
+            return 0;
+        }
+        self.offset % self.arity
+    }
+
+    pub fn next_sibling(&self) -> Option<Self> {
+        if self.depth == 0 || self.offset % self.arity == self.arity - 1 {
+            return None;
+        }
+        Self::new(self.arity, self.depth, self.offset + 1)
+    }
+
+    pub fn prev_sibling(&self) -> Option<Self> {
+        if self.depth == 0 || self.offset.is_multiple_of(self.arity) {
+            return None;
+        }
+        let arity = self.arity;
+        let depth = self.depth;
+        let offset = self.offset - 1;
+        Some(Self {
+            arity,
+            depth,
+            offset,
+        })
+    }
+
+    pub fn iter_siblings(&self) -> DynIndexRange {
+        match self.parent() {
+            Some(parent) => parent.iter_children(),
+            None => DynIndexRange::from_flattened(self.arity, 0..=0),
+        }
+    }
+
+    pub fn subtree_levels(&self, tree_len: usize) -> SubtreeLevels {
+        SubtreeLevels::new(self.arity, self.to_flattened(), tree_len)
+    }
+
+    pub fn iter_children(&self) -> DynIndexRange {
+        let Some(first) = self.first_child() else {
+            return DynIndexRange::empty(self.arity);
+        };
+        let start = first.to_flattened();
+        let end = start.saturating_add(self.arity - 1);
+        DynIndexRange::from_flattened(self.arity, start..=end)
+    }
+
+    pub fn from_flattened(arity: usize, index: usize) -> Option<Self> {
+        if arity == 0 {
+            return None;
+        }
+        let (depth, offset) = split_flattened(arity, index);
+        Some(Self {
+            arity,
+            depth,
+            offset,
+        })
+    }
+
+    pub fn to_flattened(self) -> usize {
+        let Some(start) = level_start(self.arity, self.depth) else {
+            unreachable!()
+        };
+        start + self.offset
+    }
+}
+
+impl<const N: usize> From<Index<N>> for DynIndex {
+    fn from(value: Index<N>) -> Self {
+        let arity = N;
+        let depth = value.depth();
+        let offset = value.offset();
+        Self {
+            arity,
+            depth,
+            offset,
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<DynIndex> for Index<N> {
+    type Error = Error;
+
+    fn try_from(value: DynIndex) -> Result<Self, Self::Error> {
+        if value.arity != N {
+            let expected = N;
+            let found = value.arity;
+            return Err(Error::ArityMismatch { expected, found });
+        }
+        let Some(index) = Index::new(value.depth, value.offset) else {
+            unreachable!()
+        };
+        Ok(index)
+    }
+}
+
+impl TreeIndex for DynIndex {}
+
+impl sealed::TreeIndex for DynIndex {
+    type PreOrder = traverse::PreOrder;
+
+    type PostOrder = traverse::PostOrder;
+
+    fn arity(&self) -> usize {
+        self.arity
+    }
+
+    fn to_flattened(&self) -> usize {
+        DynIndex::to_flattened(*self)
+    }
+
+    fn pre_order_from(self, tree_len: usize) -> Self::PreOrder {
+        traverse::PreOrder::new_from(self, tree_len)
+    }
+
+    fn post_order_from(self, tree_len: usize) -> Self::PostOrder {
+        traverse::PostOrder::new_from(self, tree_len)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DynIndexRange {
+    arity: usize,
+    range: RangeInclusive<usize>,
+}
+
+impl DynIndexRange {
+    pub const fn arity(&self) -> usize {
+        self.arity
+    }
+
+    pub fn len(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.range.end() - self.range.start() + 1
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    pub fn to_flattened(&self) -> RangeInclusive<usize> {
+        let start = *self.range.start();
+        let end = *self.range.end();
+        start..=end
+    }
+
+    pub(crate) fn empty(arity: usize) -> Self {
+        let start = usize::MAX;
+        let end = usize::MIN;
+        Self::from_flattened(arity, start..=end)
+    }
+
+    pub(crate) fn level(arity: usize, depth: usize) -> Self {
+        let Some(first) = DynIndex::new(arity, depth, 0) else {
+            return Self::empty(arity);
+        };
+        let start = first.to_flattened();
+        let end = match arity.checked_pow(depth as u32) {
+            Some(width) => start.saturating_add(width - 1),
+            None => usize::MAX,
+        };
+        Self::from_flattened(arity, start..=end)
+    }
+
+    pub(crate) fn cap(self, upper: usize) -> Self {
+        let start = *self.range.start();
+        let end = *self.range.end();
+        let end = if end < upper { end } else { upper - 1 };
+        Self::from_flattened(self.arity, start..=end)
+    }
+
+    fn from_flattened(arity: usize, range: RangeInclusive<usize>) -> Self {
+        debug_assert!(arity != 0, "invalid arity");
+        Self { arity, range }
+    }
+
+    fn index(&self, index: usize) -> DynIndex {
+        let Some(index) = DynIndex::from_flattened(self.arity, index) else {
+            unreachable!()
+        };
+        index
+    }
+}
+
+impl Iterator for DynIndexRange {
+    type Item = DynIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.range.next()?;
+        Some(self.index(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for DynIndexRange {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl DoubleEndedIterator for DynIndexRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.range.next_back()?;
+        Some(self.index(index))
+    }
+}
+
+impl FusedIterator for DynIndexRange {}
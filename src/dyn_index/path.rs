@@ -0,0 +1,73 @@
+use super::DynIndex;
+use crate::index::traverse::{Traversal, sealed};
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+#[derive(Debug, Clone)]
+pub struct Ancestors {
+    index: DynIndex,
+    depths: Range<usize>,
+}
+
+impl Ancestors {
+    pub(crate) fn new(index: DynIndex) -> Self {
+        let depths = 0..index.depth();
+        Self { index, depths }
+    }
+
+    fn ancestor(&self, depth: usize) -> DynIndex {
+        let Some(ancestor) = self.index.ancestor_at_depth(depth) else {
+            unreachable!()
+        };
+        ancestor
+    }
+}
+
+impl Iterator for Ancestors {
+    type Item = DynIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let depth = self.depths.next_back()?;
+        Some(self.ancestor(depth))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.depths.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Ancestors {
+    fn len(&self) -> usize {
+        self.depths.len()
+    }
+}
+
+impl DoubleEndedIterator for Ancestors {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let depth = self.depths.next()?;
+        Some(self.ancestor(depth))
+    }
+}
+
+impl FusedIterator for Ancestors {}
+
+impl Traversal for Ancestors {}
+
+impl sealed::Traversal for Ancestors {
+    fn next_flattened(&mut self) -> Option<usize> {
+        let index = self.next()?;
+        Some(index.to_flattened())
+    }
+
+    fn next_back_flattened(&mut self) -> Option<usize> {
+        let index = self.next_back()?;
+        Some(index.to_flattened())
+    }
+
+    fn index(&self, index: usize) -> Self::Item {
+        let Some(index) = DynIndex::from_flattened(self.index.arity(), index) else {
+            unreachable!()
+        };
+        index
+    }
+}
@@ -0,0 +1,32 @@
+use super::DynIndexRange;
+use crate::index::next_level;
+use core::iter::FusedIterator;
+
+#[derive(Debug, Clone)]
+pub struct SubtreeLevels {
+    arity: usize,
+    level: Option<(usize, usize)>,
+    tree_len: usize,
+}
+
+impl SubtreeLevels {
+    pub(crate) fn new(arity: usize, root: usize, tree_len: usize) -> Self {
+        let level = Some((root, root));
+        Self {
+            arity,
+            level,
+            tree_len,
+        }
+    }
+}
+
+impl Iterator for SubtreeLevels {
+    type Item = DynIndexRange;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range = next_level(self.arity, &mut self.level, self.tree_len)?;
+        Some(DynIndexRange::from_flattened(self.arity, range))
+    }
+}
+
+impl FusedIterator for SubtreeLevels {}
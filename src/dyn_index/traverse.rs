@@ -0,0 +1,347 @@
+use crate::index::traverse::{In, MaxDepth, Post, Pre, Traversal, Walk, depth_limit, sealed};
+use crate::{DynIndex, Error, Index};
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+#[derive(Debug, Clone)]
+pub struct LevelOrder {
+    arity: usize,
+    range: Range<usize>,
+}
+
+impl LevelOrder {
+    pub fn new(arity: usize, tree_len: usize) -> Option<Self> {
+        if arity == 0 {
+            return None;
+        }
+        let range = 0..tree_len;
+        Some(Self { arity, range })
+    }
+
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.range.end = depth_limit(self.arity, depth, self.range.end);
+        self.range.start = self.range.start.min(self.range.end);
+        self
+    }
+
+    pub(crate) fn next_flattened(&mut self) -> Option<usize> {
+        self.range.next()
+    }
+
+    pub(crate) fn next_back_flattened(&mut self) -> Option<usize> {
+        self.range.next_back()
+    }
+}
+
+impl Iterator for LevelOrder {
+    type Item = DynIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next_flattened()?;
+        Some(from_flattened(self.arity, index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl ExactSizeIterator for LevelOrder {
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+}
+
+impl DoubleEndedIterator for LevelOrder {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.next_back_flattened()?;
+        Some(from_flattened(self.arity, index))
+    }
+}
+
+impl FusedIterator for LevelOrder {}
+
+impl Traversal for LevelOrder {}
+
+impl sealed::Traversal for LevelOrder {
+    fn next_flattened(&mut self) -> Option<usize> {
+        self.next_flattened()
+    }
+
+    fn next_back_flattened(&mut self) -> Option<usize> {
+        self.next_back_flattened()
+    }
+
+    fn index(&self, index: usize) -> Self::Item {
+        from_flattened(self.arity, index)
+    }
+}
+
+impl MaxDepth for LevelOrder {}
+
+impl sealed::MaxDepth for LevelOrder {
+    fn max_depth(self, depth: usize) -> Self {
+        self.max_depth(depth)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PreOrder {
+    arity: usize,
+    walk: Walk<Pre>,
+}
+
+impl PreOrder {
+    pub fn new(arity: usize, tree_len: usize) -> Option<Self> {
+        let root = DynIndex::root(arity)?;
+        Some(Self::new_from(root, tree_len))
+    }
+
+    pub fn new_from(root: DynIndex, tree_len: usize) -> Self {
+        let arity = root.arity();
+        let walk = Walk::new(arity, root.to_flattened(), tree_len);
+        Self { arity, walk }
+    }
+
+    pub fn max_depth(self, depth: usize) -> Self {
+        let walk = self.walk.max_depth(self.arity, depth);
+        Self { walk, ..self }
+    }
+
+    pub(crate) fn next_flattened(&mut self) -> Option<usize> {
+        self.walk.next(self.arity)
+    }
+
+    pub(crate) fn next_back_flattened(&mut self) -> Option<usize> {
+        self.walk.next_back(self.arity)
+    }
+}
+
+impl Iterator for PreOrder {
+    type Item = DynIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next_flattened()?;
+        Some(from_flattened(self.arity, index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.walk.len(), Some(self.walk.len()))
+    }
+}
+
+impl ExactSizeIterator for PreOrder {
+    fn len(&self) -> usize {
+        self.walk.len()
+    }
+}
+
+impl DoubleEndedIterator for PreOrder {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.next_back_flattened()?;
+        Some(from_flattened(self.arity, index))
+    }
+}
+
+impl FusedIterator for PreOrder {}
+
+impl Traversal for PreOrder {}
+
+impl sealed::Traversal for PreOrder {
+    fn next_flattened(&mut self) -> Option<usize> {
+        self.next_flattened()
+    }
+
+    fn next_back_flattened(&mut self) -> Option<usize> {
+        self.next_back_flattened()
+    }
+
+    fn index(&self, index: usize) -> Self::Item {
+        from_flattened(self.arity, index)
+    }
+}
+
+impl MaxDepth for PreOrder {}
+
+impl sealed::MaxDepth for PreOrder {
+    fn max_depth(self, depth: usize) -> Self {
+        self.max_depth(depth)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostOrder {
+    arity: usize,
+    walk: Walk<Post>,
+}
+
+impl PostOrder {
+    pub fn new(arity: usize, tree_len: usize) -> Option<Self> {
+        let root = DynIndex::root(arity)?;
+        Some(Self::new_from(root, tree_len))
+    }
+
+    pub fn new_from(root: DynIndex, tree_len: usize) -> Self {
+        let arity = root.arity();
+        let walk = Walk::new(arity, root.to_flattened(), tree_len);
+        Self { arity, walk }
+    }
+
+    pub fn max_depth(self, depth: usize) -> Self {
+        let walk = self.walk.max_depth(self.arity, depth);
+        Self { walk, ..self }
+    }
+
+    pub(crate) fn next_flattened(&mut self) -> Option<usize> {
+        self.walk.next(self.arity)
+    }
+
+    pub(crate) fn next_back_flattened(&mut self) -> Option<usize> {
+        self.walk.next_back(self.arity)
+    }
+}
+
+impl Iterator for PostOrder {
+    type Item = DynIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next_flattened()?;
+        Some(from_flattened(self.arity, index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.walk.len(), Some(self.walk.len()))
+    }
+}
+
+impl ExactSizeIterator for PostOrder {
+    fn len(&self) -> usize {
+        self.walk.len()
+    }
+}
+
+impl DoubleEndedIterator for PostOrder {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.next_back_flattened()?;
+        Some(from_flattened(self.arity, index))
+    }
+}
+
+impl FusedIterator for PostOrder {}
+
+impl Traversal for PostOrder {}
+
+impl sealed::Traversal for PostOrder {
+    fn next_flattened(&mut self) -> Option<usize> {
+        self.next_flattened()
+    }
+
+    fn next_back_flattened(&mut self) -> Option<usize> {
+        self.next_back_flattened()
+    }
+
+    fn index(&self, index: usize) -> Self::Item {
+        from_flattened(self.arity, index)
+    }
+}
+
+impl MaxDepth for PostOrder {}
+
+impl sealed::MaxDepth for PostOrder {
+    fn max_depth(self, depth: usize) -> Self {
+        self.max_depth(depth)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InOrder {
+    walk: Walk<In>,
+}
+
+impl InOrder {
+    pub fn new(tree_len: usize) -> Self {
+        Self::from_root(Index::root(), tree_len)
+    }
+
+    pub fn new_from(root: DynIndex, tree_len: usize) -> Result<Self, Error> {
+        let root = Index::<2>::try_from(root)?;
+        Ok(Self::from_root(root, tree_len))
+    }
+
+    pub fn max_depth(self, depth: usize) -> Self {
+        let walk = self.walk.max_depth(2, depth);
+        Self { walk }
+    }
+
+    pub(crate) fn next_flattened(&mut self) -> Option<usize> {
+        self.walk.next(2)
+    }
+
+    pub(crate) fn next_back_flattened(&mut self) -> Option<usize> {
+        self.walk.next_back(2)
+    }
+
+    fn from_root(root: Index<2>, tree_len: usize) -> Self {
+        let walk = Walk::new(2, root.to_flattened(), tree_len);
+        Self { walk }
+    }
+}
+
+impl Iterator for InOrder {
+    type Item = DynIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next_flattened()?;
+        Some(from_flattened(2, index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.walk.len(), Some(self.walk.len()))
+    }
+}
+
+impl ExactSizeIterator for InOrder {
+    fn len(&self) -> usize {
+        self.walk.len()
+    }
+}
+
+impl DoubleEndedIterator for InOrder {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.next_back_flattened()?;
+        Some(from_flattened(2, index))
+    }
+}
+
+impl FusedIterator for InOrder {}
+
+impl Traversal for InOrder {}
+
+impl sealed::Traversal for InOrder {
+    fn next_flattened(&mut self) -> Option<usize> {
+        self.next_flattened()
+    }
+
+    fn next_back_flattened(&mut self) -> Option<usize> {
+        self.next_back_flattened()
+    }
+
+    fn index(&self, index: usize) -> Self::Item {
+        from_flattened(2, index)
+    }
+}
+
+impl MaxDepth for InOrder {}
+
+impl sealed::MaxDepth for InOrder {
+    fn max_depth(self, depth: usize) -> Self {
+        self.max_depth(depth)
+    }
+}
+
+fn from_flattened(arity: usize, index: usize) -> DynIndex {
+    let Some(index) = DynIndex::from_flattened(arity, index) else {
+        unreachable!()
+    };
+    index
+}
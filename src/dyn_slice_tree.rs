@@ -0,0 +1,757 @@
+pub use crate::slice_tree::{IterLevels, IterLevelsMut, Levels};
+
+use self::traverse::{
+    InOrder, InOrderIndexed, InOrderIndexedMut, InOrderMut, LevelOrderIndexed,
+    LevelOrderIndexedMut, Nodes, PostOrder, PostOrderIndexed, PostOrderIndexedMut, PostOrderMut,
+    PreOrder, PreOrderIndexed, PreOrderIndexedMut, PreOrderMut,
+};
+use crate::dyn_index::{self, traverse as indices};
+use crate::{
+    DynCompleteTree, DynCompleteTreeMut, DynIndex, DynIndexRange, Error, Index, SliceTree, index,
+    slice_tree,
+};
+use core::mem;
+use core::ops::{RangeBounds, RangeInclusive};
+use core::slice::{Iter, IterMut};
+
+pub mod traverse;
+
+pub type Ancestors<'a, T> = Nodes<'a, dyn_index::Ancestors, T>;
+pub type Subtree<'a, T> = slice_tree::Subtree<'a, DynIndex, T>;
+
+#[derive(Debug)]
+pub struct DynSliceTree<'a, T> {
+    arity: usize,
+    nodes: &'a [T],
+}
+
+impl<'a, T> DynSliceTree<'a, T> {
+    pub fn new(arity: usize, nodes: &'a [T]) -> Option<Self> {
+        if arity == 0 {
+            return None;
+        }
+        Some(Self { arity, nodes })
+    }
+
+    pub fn as_slice(&self) -> &'a [T] {
+        self.nodes
+    }
+}
+
+impl<T> Clone for DynSliceTree<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DynSliceTree<'_, T> {}
+
+impl<'a, const N: usize, T> From<&'a SliceTree<N, T>> for DynSliceTree<'a, T> {
+    fn from(value: &'a SliceTree<N, T>) -> Self {
+        let arity = N;
+        let nodes = value.into();
+        Self { arity, nodes }
+    }
+}
+
+impl<'a, T> From<DynSliceTreeMut<'a, T>> for DynSliceTree<'a, T> {
+    fn from(value: DynSliceTreeMut<'a, T>) -> Self {
+        let arity = value.arity;
+        let nodes = value.nodes;
+        Self { arity, nodes }
+    }
+}
+
+impl<'a, const N: usize, T> TryFrom<DynSliceTree<'a, T>> for &'a SliceTree<N, T> {
+    type Error = Error;
+
+    fn try_from(value: DynSliceTree<'a, T>) -> Result<Self, Self::Error> {
+        if value.arity != N {
+            let expected = N;
+            let found = value.arity;
+            return Err(Error::ArityMismatch { expected, found });
+        }
+        Ok(value.nodes.into())
+    }
+}
+
+impl<T> DynCompleteTree for DynSliceTree<'_, T> {
+    type Node = T;
+
+    type IterChildren<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevel<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type LevelOrder<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type PreOrder<'a>
+        = PreOrder<'a, T>
+    where
+        Self: 'a;
+
+    type PostOrder<'a>
+        = PostOrder<'a, T>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexed<'a>
+        = LevelOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type PreOrderIndexed<'a>
+        = PreOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type PostOrderIndexed<'a>
+        = PostOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type InOrder<'a>
+        = InOrder<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderIndexed<'a>
+        = InOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type Ancestors<'a>
+        = Ancestors<'a, T>
+    where
+        Self: 'a;
+
+    type IterSiblings<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type Subtree<'a>
+        = Subtree<'a, T>
+    where
+        Self: 'a;
+
+    type IterLeaves<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevels<'a>
+        = IterLevels<'a, T>
+    where
+        Self: 'a;
+
+    fn arity(&self) -> usize {
+        self.arity
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn node(&self, index: DynIndex) -> Option<&Self::Node> {
+        let index = flattened(self.arity, self.nodes, index)?;
+        self.nodes.get(index)
+    }
+
+    fn ancestors(&self, index: DynIndex) -> Option<Self::Ancestors<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        Some(Ancestors::with_indices(index.ancestors(), self.nodes))
+    }
+
+    fn iter_siblings(&self, index: DynIndex) -> Option<Self::IterSiblings<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        let siblings = index.iter_siblings().cap(self.nodes.len()).to_flattened();
+        self.nodes.get(siblings).map(<[T]>::iter)
+    }
+
+    fn iter_children(&self, index: DynIndex) -> Option<Self::IterChildren<'_>> {
+        let children = children(self.arity, self.nodes, index)?;
+        self.nodes.get(children).map(<[T]>::iter)
+    }
+
+    fn subtree(&self, index: DynIndex) -> Option<Self::Subtree<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        Some(Subtree::new(index, self.nodes))
+    }
+
+    fn iter_level(&self, depth: usize) -> Option<Self::IterLevel<'_>> {
+        let level = level(self.arity, self.nodes, depth)?;
+        self.nodes.get(level).map(<[T]>::iter)
+    }
+
+    fn iter_leaves(&self) -> Self::IterLeaves<'_> {
+        let internal = self.internal_count();
+        self.nodes[internal..].iter()
+    }
+
+    fn iter_levels<R>(&self, depths: R) -> Self::IterLevels<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        IterLevels::new(self.arity, depths, self.nodes)
+    }
+
+    fn traverse_level_order(&self) -> Self::LevelOrder<'_> {
+        self.nodes.iter()
+    }
+
+    fn traverse_pre_order(&self) -> Self::PreOrder<'_> {
+        let Some(nodes) = PreOrder::new(self.arity, self.nodes) else {
+            unreachable!()
+        };
+        nodes
+    }
+
+    fn traverse_post_order(&self) -> Self::PostOrder<'_> {
+        let Some(nodes) = PostOrder::new(self.arity, self.nodes) else {
+            unreachable!()
+        };
+        nodes
+    }
+
+    fn traverse_pre_order_from(&self, index: DynIndex) -> Option<Self::PreOrder<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        let indices = indices::PreOrder::new_from(index, self.nodes.len());
+        Some(PreOrder::with_indices(indices, self.nodes))
+    }
+
+    fn traverse_post_order_from(&self, index: DynIndex) -> Option<Self::PostOrder<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        let indices = indices::PostOrder::new_from(index, self.nodes.len());
+        Some(PostOrder::with_indices(indices, self.nodes))
+    }
+
+    fn traverse_level_order_indexed(&self) -> Self::LevelOrderIndexed<'_> {
+        let Some(indices) = indices::LevelOrder::new(self.arity, self.nodes.len()) else {
+            unreachable!()
+        };
+        LevelOrderIndexed::new(indices, self.nodes)
+    }
+
+    fn traverse_pre_order_indexed(&self) -> Self::PreOrderIndexed<'_> {
+        let Some(indices) = indices::PreOrder::new(self.arity, self.nodes.len()) else {
+            unreachable!()
+        };
+        PreOrderIndexed::new(indices, self.nodes)
+    }
+
+    fn traverse_post_order_indexed(&self) -> Self::PostOrderIndexed<'_> {
+        let Some(indices) = indices::PostOrder::new(self.arity, self.nodes.len()) else {
+            unreachable!()
+        };
+        PostOrderIndexed::new(indices, self.nodes)
+    }
+
+    fn traverse_in_order(&self) -> Result<Self::InOrder<'_>, Error> {
+        binary(self.arity)?;
+        Ok(InOrder::new(self.nodes))
+    }
+
+    fn traverse_in_order_from(&self, index: DynIndex) -> Result<Self::InOrder<'_>, Error> {
+        let root = binary_root(self.arity, self.nodes, index)?;
+        let indices = index::traverse::InOrder::new_from(root, self.nodes.len());
+        Ok(InOrder::with_indices(indices, self.nodes))
+    }
+
+    fn traverse_in_order_indexed(&self) -> Result<Self::InOrderIndexed<'_>, Error> {
+        binary(self.arity)?;
+        let indices = indices::InOrder::new(self.nodes.len());
+        Ok(InOrderIndexed::new(indices, self.nodes))
+    }
+}
+
+#[derive(Debug)]
+pub struct DynSliceTreeMut<'a, T> {
+    arity: usize,
+    nodes: &'a mut [T],
+}
+
+impl<'a, T> DynSliceTreeMut<'a, T> {
+    pub fn new(arity: usize, nodes: &'a mut [T]) -> Option<Self> {
+        if arity == 0 {
+            return None;
+        }
+        Some(Self { arity, nodes })
+    }
+
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.nodes
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.nodes
+    }
+
+    pub fn as_dyn_slice_tree(&self) -> DynSliceTree<'_, T> {
+        let arity = self.arity;
+        let nodes = &*self.nodes;
+        DynSliceTree { arity, nodes }
+    }
+}
+
+impl<'a, const N: usize, T> From<&'a mut SliceTree<N, T>> for DynSliceTreeMut<'a, T> {
+    fn from(value: &'a mut SliceTree<N, T>) -> Self {
+        let arity = N;
+        let nodes = value.into();
+        Self { arity, nodes }
+    }
+}
+
+impl<'a, const N: usize, T> TryFrom<DynSliceTreeMut<'a, T>> for &'a mut SliceTree<N, T> {
+    type Error = Error;
+
+    fn try_from(value: DynSliceTreeMut<'a, T>) -> Result<Self, Self::Error> {
+        if value.arity != N {
+            let expected = N;
+            let found = value.arity;
+            return Err(Error::ArityMismatch { expected, found });
+        }
+        Ok(value.nodes.into())
+    }
+}
+
+impl<T> DynCompleteTree for DynSliceTreeMut<'_, T> {
+    type Node = T;
+
+    type IterChildren<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevel<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type LevelOrder<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type PreOrder<'a>
+        = PreOrder<'a, T>
+    where
+        Self: 'a;
+
+    type PostOrder<'a>
+        = PostOrder<'a, T>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexed<'a>
+        = LevelOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type PreOrderIndexed<'a>
+        = PreOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type PostOrderIndexed<'a>
+        = PostOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type InOrder<'a>
+        = InOrder<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderIndexed<'a>
+        = InOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type Ancestors<'a>
+        = Ancestors<'a, T>
+    where
+        Self: 'a;
+
+    type IterSiblings<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type Subtree<'a>
+        = Subtree<'a, T>
+    where
+        Self: 'a;
+
+    type IterLeaves<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevels<'a>
+        = IterLevels<'a, T>
+    where
+        Self: 'a;
+
+    fn arity(&self) -> usize {
+        self.arity
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn node(&self, index: DynIndex) -> Option<&Self::Node> {
+        let index = flattened(self.arity, self.nodes, index)?;
+        self.nodes.get(index)
+    }
+
+    fn ancestors(&self, index: DynIndex) -> Option<Self::Ancestors<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        Some(Ancestors::with_indices(index.ancestors(), self.nodes))
+    }
+
+    fn iter_siblings(&self, index: DynIndex) -> Option<Self::IterSiblings<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        let siblings = index.iter_siblings().cap(self.nodes.len()).to_flattened();
+        self.nodes.get(siblings).map(<[T]>::iter)
+    }
+
+    fn iter_children(&self, index: DynIndex) -> Option<Self::IterChildren<'_>> {
+        let children = children(self.arity, self.nodes, index)?;
+        self.nodes.get(children).map(<[T]>::iter)
+    }
+
+    fn subtree(&self, index: DynIndex) -> Option<Self::Subtree<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        Some(Subtree::new(index, self.nodes))
+    }
+
+    fn iter_level(&self, depth: usize) -> Option<Self::IterLevel<'_>> {
+        let level = level(self.arity, self.nodes, depth)?;
+        self.nodes.get(level).map(<[T]>::iter)
+    }
+
+    fn iter_leaves(&self) -> Self::IterLeaves<'_> {
+        let internal = self.internal_count();
+        self.nodes[internal..].iter()
+    }
+
+    fn iter_levels<R>(&self, depths: R) -> Self::IterLevels<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        IterLevels::new(self.arity, depths, self.nodes)
+    }
+
+    fn traverse_level_order(&self) -> Self::LevelOrder<'_> {
+        self.nodes.iter()
+    }
+
+    fn traverse_pre_order(&self) -> Self::PreOrder<'_> {
+        let Some(nodes) = PreOrder::new(self.arity, self.nodes) else {
+            unreachable!()
+        };
+        nodes
+    }
+
+    fn traverse_post_order(&self) -> Self::PostOrder<'_> {
+        let Some(nodes) = PostOrder::new(self.arity, self.nodes) else {
+            unreachable!()
+        };
+        nodes
+    }
+
+    fn traverse_pre_order_from(&self, index: DynIndex) -> Option<Self::PreOrder<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        let indices = indices::PreOrder::new_from(index, self.nodes.len());
+        Some(PreOrder::with_indices(indices, self.nodes))
+    }
+
+    fn traverse_post_order_from(&self, index: DynIndex) -> Option<Self::PostOrder<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        let indices = indices::PostOrder::new_from(index, self.nodes.len());
+        Some(PostOrder::with_indices(indices, self.nodes))
+    }
+
+    fn traverse_level_order_indexed(&self) -> Self::LevelOrderIndexed<'_> {
+        let Some(indices) = indices::LevelOrder::new(self.arity, self.nodes.len()) else {
+            unreachable!()
+        };
+        LevelOrderIndexed::new(indices, self.nodes)
+    }
+
+    fn traverse_pre_order_indexed(&self) -> Self::PreOrderIndexed<'_> {
+        let Some(indices) = indices::PreOrder::new(self.arity, self.nodes.len()) else {
+            unreachable!()
+        };
+        PreOrderIndexed::new(indices, self.nodes)
+    }
+
+    fn traverse_post_order_indexed(&self) -> Self::PostOrderIndexed<'_> {
+        let Some(indices) = indices::PostOrder::new(self.arity, self.nodes.len()) else {
+            unreachable!()
+        };
+        PostOrderIndexed::new(indices, self.nodes)
+    }
+
+    fn traverse_in_order(&self) -> Result<Self::InOrder<'_>, Error> {
+        binary(self.arity)?;
+        Ok(InOrder::new(self.nodes))
+    }
+
+    fn traverse_in_order_from(&self, index: DynIndex) -> Result<Self::InOrder<'_>, Error> {
+        let root = binary_root(self.arity, self.nodes, index)?;
+        let indices = index::traverse::InOrder::new_from(root, self.nodes.len());
+        Ok(InOrder::with_indices(indices, self.nodes))
+    }
+
+    fn traverse_in_order_indexed(&self) -> Result<Self::InOrderIndexed<'_>, Error> {
+        binary(self.arity)?;
+        let indices = indices::InOrder::new(self.nodes.len());
+        Ok(InOrderIndexed::new(indices, self.nodes))
+    }
+}
+
+impl<T> DynCompleteTreeMut for DynSliceTreeMut<'_, T> {
+    type IterChildrenMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevelMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type LevelOrderMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type PreOrderMut<'a>
+        = PreOrderMut<'a, T>
+    where
+        Self: 'a;
+
+    type PostOrderMut<'a>
+        = PostOrderMut<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderMut<'a>
+        = InOrderMut<'a, T>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexedMut<'a>
+        = LevelOrderIndexedMut<'a, T>
+    where
+        Self: 'a;
+
+    type PreOrderIndexedMut<'a>
+        = PreOrderIndexedMut<'a, T>
+    where
+        Self: 'a;
+
+    type PostOrderIndexedMut<'a>
+        = PostOrderIndexedMut<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderIndexedMut<'a>
+        = InOrderIndexedMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterSiblingsMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterLeavesMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevelsMut<'a>
+        = IterLevelsMut<'a, T>
+    where
+        Self: 'a;
+
+    fn swap(&mut self, index_a: DynIndex, index_b: DynIndex) -> Option<()> {
+        let index_a = flattened(self.arity, self.nodes, index_a)?;
+        let index_b = flattened(self.arity, self.nodes, index_b)?;
+        self.nodes.swap(index_a, index_b);
+        Some(())
+    }
+
+    fn replace(&mut self, index: DynIndex, node: T) -> Option<T> {
+        let old = self.node_mut(index)?;
+        Some(mem::replace(old, node))
+    }
+
+    fn node_mut(&mut self, index: DynIndex) -> Option<&mut T> {
+        let index = flattened(self.arity, self.nodes, index)?;
+        self.nodes.get_mut(index)
+    }
+
+    fn iter_siblings_mut(&mut self, index: DynIndex) -> Option<Self::IterSiblingsMut<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        let siblings = index.iter_siblings().cap(self.nodes.len()).to_flattened();
+        self.nodes.get_mut(siblings).map(<[T]>::iter_mut)
+    }
+
+    fn iter_children_mut(&mut self, index: DynIndex) -> Option<Self::IterChildrenMut<'_>> {
+        let children = children(self.arity, self.nodes, index)?;
+        self.nodes.get_mut(children).map(<[T]>::iter_mut)
+    }
+
+    fn iter_level_mut(&mut self, depth: usize) -> Option<Self::IterLevelMut<'_>> {
+        let level = level(self.arity, self.nodes, depth)?;
+        self.nodes.get_mut(level).map(<[T]>::iter_mut)
+    }
+
+    fn iter_leaves_mut(&mut self) -> Self::IterLeavesMut<'_> {
+        let internal = self.internal_count();
+        self.nodes[internal..].iter_mut()
+    }
+
+    fn iter_levels_mut<R>(&mut self, depths: R) -> Self::IterLevelsMut<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        IterLevelsMut::new(self.arity, depths, self.nodes)
+    }
+
+    fn traverse_level_order_mut(&mut self) -> Self::LevelOrderMut<'_> {
+        self.nodes.iter_mut()
+    }
+
+    fn traverse_pre_order_mut(&mut self) -> Self::PreOrderMut<'_> {
+        let Some(nodes) = PreOrderMut::new(self.arity, self.nodes) else {
+            unreachable!()
+        };
+        nodes
+    }
+
+    fn traverse_post_order_mut(&mut self) -> Self::PostOrderMut<'_> {
+        let Some(nodes) = PostOrderMut::new(self.arity, self.nodes) else {
+            unreachable!()
+        };
+        nodes
+    }
+
+    fn traverse_pre_order_from_mut(&mut self, index: DynIndex) -> Option<Self::PreOrderMut<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        let indices = indices::PreOrder::new_from(index, self.nodes.len());
+        Some(PreOrderMut::with_indices(indices, self.nodes))
+    }
+
+    fn traverse_post_order_from_mut(&mut self, index: DynIndex) -> Option<Self::PostOrderMut<'_>> {
+        flattened(self.arity, self.nodes, index)?;
+        let indices = indices::PostOrder::new_from(index, self.nodes.len());
+        Some(PostOrderMut::with_indices(indices, self.nodes))
+    }
+
+    fn traverse_level_order_indexed_mut(&mut self) -> Self::LevelOrderIndexedMut<'_> {
+        let Some(indices) = indices::LevelOrder::new(self.arity, self.nodes.len()) else {
+            unreachable!()
+        };
+        LevelOrderIndexedMut::new(indices, self.nodes)
+    }
+
+    fn traverse_pre_order_indexed_mut(&mut self) -> Self::PreOrderIndexedMut<'_> {
+        let Some(indices) = indices::PreOrder::new(self.arity, self.nodes.len()) else {
+            unreachable!()
+        };
+        PreOrderIndexedMut::new(indices, self.nodes)
+    }
+
+    fn traverse_post_order_indexed_mut(&mut self) -> Self::PostOrderIndexedMut<'_> {
+        let Some(indices) = indices::PostOrder::new(self.arity, self.nodes.len()) else {
+            unreachable!()
+        };
+        PostOrderIndexedMut::new(indices, self.nodes)
+    }
+
+    fn traverse_in_order_mut(&mut self) -> Result<Self::InOrderMut<'_>, Error> {
+        binary(self.arity)?;
+        Ok(InOrderMut::new(self.nodes))
+    }
+
+    fn traverse_in_order_from_mut(
+        &mut self,
+        index: DynIndex,
+    ) -> Result<Self::InOrderMut<'_>, Error> {
+        let root = binary_root(self.arity, self.nodes, index)?;
+        let indices = index::traverse::InOrder::new_from(root, self.nodes.len());
+        Ok(InOrderMut::with_indices(indices, self.nodes))
+    }
+
+    fn traverse_in_order_indexed_mut(&mut self) -> Result<Self::InOrderIndexedMut<'_>, Error> {
+        binary(self.arity)?;
+        let indices = indices::InOrder::new(self.nodes.len());
+        Ok(InOrderIndexedMut::new(indices, self.nodes))
+    }
+}
+
+fn flattened<T>(arity: usize, nodes: &[T], index: DynIndex) -> Option<usize> {
+    if index.arity() != arity {
+        return None;
+    }
+    let index = index.to_flattened();
+    (index < nodes.len()).then_some(index)
+}
+
+fn children<T>(arity: usize, nodes: &[T], index: DynIndex) -> Option<RangeInclusive<usize>> {
+    flattened(arity, nodes, index)?;
+    let children = index.iter_children().cap(nodes.len());
+    Some(children.to_flattened())
+}
+
+fn level<T>(arity: usize, nodes: &[T], depth: usize) -> Option<RangeInclusive<usize>> {
+    if nodes.is_empty() {
+        return None;
+    }
+    let Some(last) = DynIndex::from_flattened(arity, nodes.len() - 1) else {
+        unreachable!()
+    };
+    if depth > last.depth() {
+        return None;
+    }
+    let level = DynIndexRange::level(arity, depth).cap(nodes.len());
+    Some(level.to_flattened())
+}
+
+fn binary(arity: usize) -> Result<(), Error> {
+    if arity != 2 {
+        let expected = 2;
+        let found = arity;
+        return Err(Error::ArityMismatch { expected, found });
+    }
+    Ok(())
+}
+
+fn binary_root<T>(arity: usize, nodes: &[T], index: DynIndex) -> Result<Index<2>, Error> {
+    binary(arity)?;
+    let root = Index::<2>::try_from(index)?;
+    if root.to_flattened() >= nodes.len() {
+        let index = root.to_flattened();
+        let len = nodes.len();
+        return Err(Error::OutOfBounds { index, len });
+    }
+    Ok(root)
+}
@@ -0,0 +1,45 @@
+pub use crate::slice_tree::traverse::{InOrder, InOrderMut, Indexed, IndexedMut, Nodes, NodesMut};
+
+use crate::dyn_index::traverse as indices;
+
+pub type PreOrder<'a, T> = Nodes<'a, indices::PreOrder, T>;
+pub type PreOrderMut<'a, T> = NodesMut<'a, indices::PreOrder, T>;
+pub type PostOrder<'a, T> = Nodes<'a, indices::PostOrder, T>;
+pub type PostOrderMut<'a, T> = NodesMut<'a, indices::PostOrder, T>;
+
+pub type LevelOrderIndexed<'a, T> = Indexed<'a, indices::LevelOrder, T>;
+pub type LevelOrderIndexedMut<'a, T> = IndexedMut<'a, indices::LevelOrder, T>;
+pub type PreOrderIndexed<'a, T> = Indexed<'a, indices::PreOrder, T>;
+pub type PreOrderIndexedMut<'a, T> = IndexedMut<'a, indices::PreOrder, T>;
+pub type PostOrderIndexed<'a, T> = Indexed<'a, indices::PostOrder, T>;
+pub type PostOrderIndexedMut<'a, T> = IndexedMut<'a, indices::PostOrder, T>;
+pub type InOrderIndexed<'a, T> = Indexed<'a, indices::InOrder, T>;
+pub type InOrderIndexedMut<'a, T> = IndexedMut<'a, indices::InOrder, T>;
+
+impl<'a, T> PreOrder<'a, T> {
+    pub fn new(arity: usize, tree: &'a [T]) -> Option<Self> {
+        let indices = indices::PreOrder::new(arity, tree.len())?;
+        Some(Self::with_indices(indices, tree))
+    }
+}
+
+impl<'a, T> PreOrderMut<'a, T> {
+    pub fn new(arity: usize, tree: &'a mut [T]) -> Option<Self> {
+        let indices = indices::PreOrder::new(arity, tree.len())?;
+        Some(Self::with_indices(indices, tree))
+    }
+}
+
+impl<'a, T> PostOrder<'a, T> {
+    pub fn new(arity: usize, tree: &'a [T]) -> Option<Self> {
+        let indices = indices::PostOrder::new(arity, tree.len())?;
+        Some(Self::with_indices(indices, tree))
+    }
+}
+
+impl<'a, T> PostOrderMut<'a, T> {
+    pub fn new(arity: usize, tree: &'a mut [T]) -> Option<Self> {
+        let indices = indices::PostOrder::new(arity, tree.len())?;
+        Some(Self::with_indices(indices, tree))
+    }
+}
@@ -0,0 +1,34 @@
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    OutOfBounds { index: usize, len: usize },
+    DepthOverflow { depth: usize, max_depth: usize },
+    OffsetOutOfLevel { depth: usize, offset: usize },
+    ChildOutOfRange { n: usize, arity: usize },
+    ArityMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { index, len } => {
+                write!(f, "index {index} out of bounds for tree of length {len}")
+            }
+            Self::DepthOverflow { depth, max_depth } => {
+                write!(f, "depth {depth} exceeds maximum depth {max_depth}")
+            }
+            Self::OffsetOutOfLevel { depth, offset } => {
+                write!(f, "offset {offset} out of range for level at depth {depth}")
+            }
+            Self::ChildOutOfRange { n, arity } => {
+                write!(f, "child {n} out of range for arity {arity}")
+            }
+            Self::ArityMismatch { expected, found } => {
+                write!(f, "expected arity {expected}, found arity {found}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
@@ -0,0 +1,222 @@
+use crate::display::{Node, NodeFormatter};
+use crate::index::traverse::LevelOrder;
+use crate::{CompleteTree, Index, IndexRange};
+use core::fmt::{self, Write};
+
+#[derive(Debug, Clone)]
+pub struct GraphOptions<'a, const N: usize, F> {
+    formatter: F,
+    highlight: &'a [Index<N>],
+    group_levels: bool,
+}
+
+impl<'a, const N: usize, F> GraphOptions<'a, N, F> {
+    pub fn new(formatter: F) -> Self {
+        Self {
+            formatter,
+            highlight: &[],
+            group_levels: false,
+        }
+    }
+
+    pub fn highlight(mut self, indices: &'a [Index<N>]) -> Self {
+        self.highlight = indices;
+        self
+    }
+
+    pub fn group_levels(mut self, group_levels: bool) -> Self {
+        self.group_levels = group_levels;
+        self
+    }
+
+    fn is_highlighted(&self, index: Index<N>) -> bool {
+        self.highlight.contains(&index)
+    }
+
+    fn is_edge_highlighted(&self, index: Index<N>) -> bool {
+        index
+            .parent()
+            .is_some_and(|parent| self.is_highlighted(parent) && self.is_highlighted(index))
+    }
+}
+
+impl<const N: usize, T: fmt::Display> Default for GraphOptions<'_, N, NodeFormatter<T>> {
+    fn default() -> Self {
+        Self::new(<T as fmt::Display>::fmt)
+    }
+}
+
+pub(crate) fn write_dot<const N: usize, T, W, F>(
+    tree: &T,
+    out: &mut W,
+    options: &GraphOptions<'_, N, F>,
+) -> fmt::Result
+where
+    T: CompleteTree<N> + ?Sized,
+    W: Write + ?Sized,
+    F: Fn(&T::Node, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    let node = |out: &mut W, index: Index<N>, indent: &str| -> fmt::Result {
+        let Some(node) = tree.node(index) else {
+            unreachable!()
+        };
+        write!(out, "{indent}n{} [label=\"", index.to_flattened())?;
+        write!(Escape::dot(out), "{}", Node(node, &options.formatter))?;
+        out.write_char('"')?;
+        if options.is_highlighted(index) {
+            out.write_str(", style=filled, fillcolor=\"#ffd54f\"")?;
+        }
+        out.write_str("];\n")
+    };
+
+    out.write_str("digraph {\n")?;
+    if options.group_levels && !tree.is_empty() {
+        for depth in 0..=tree.height() {
+            writeln!(out, "    subgraph level_{depth} {{")?;
+            out.write_str("        rank=same;\n")?;
+            for index in IndexRange::<N>::level(depth).cap(tree.len()) {
+                node(out, index, "        ")?;
+            }
+            out.write_str("    }\n")?;
+        }
+    } else {
+        for index in LevelOrder::<N>::new(tree.len()) {
+            node(out, index, "    ")?;
+        }
+    }
+
+    for index in LevelOrder::<N>::new(tree.len()).skip(1) {
+        let Some(parent) = index.parent() else {
+            unreachable!()
+        };
+        write!(
+            out,
+            "    n{} -> n{}",
+            parent.to_flattened(),
+            index.to_flattened()
+        )?;
+        if options.is_edge_highlighted(index) {
+            out.write_str(" [color=\"#e65100\", penwidth=2]")?;
+        }
+        out.write_str(";\n")?;
+    }
+    out.write_str("}\n")
+}
+
+pub(crate) fn write_mermaid<const N: usize, T, W, F>(
+    tree: &T,
+    out: &mut W,
+    options: &GraphOptions<'_, N, F>,
+) -> fmt::Result
+where
+    T: CompleteTree<N> + ?Sized,
+    W: Write + ?Sized,
+    F: Fn(&T::Node, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    let node = |out: &mut W, index: Index<N>, indent: &str| -> fmt::Result {
+        let Some(node) = tree.node(index) else {
+            unreachable!()
+        };
+        write!(out, "{indent}n{}[\"", index.to_flattened())?;
+        write!(Escape::mermaid(out), "{}", Node(node, &options.formatter))?;
+        out.write_str("\"]\n")
+    };
+
+    out.write_str("flowchart TD\n")?;
+    if options.group_levels && !tree.is_empty() {
+        for depth in 0..=tree.height() {
+            writeln!(out, "    subgraph level_{depth} [\"depth {depth}\"]")?;
+            for index in IndexRange::<N>::level(depth).cap(tree.len()) {
+                node(out, index, "        ")?;
+            }
+            out.write_str("    end\n")?;
+        }
+    } else {
+        for index in LevelOrder::<N>::new(tree.len()) {
+            node(out, index, "    ")?;
+        }
+    }
+
+    for index in LevelOrder::<N>::new(tree.len()).skip(1) {
+        let Some(parent) = index.parent() else {
+            unreachable!()
+        };
+        writeln!(
+            out,
+            "    n{} --> n{}",
+            parent.to_flattened(),
+            index.to_flattened()
+        )?;
+    }
+
+    let mut highlighted = LevelOrder::<N>::new(tree.len())
+        .filter(|&index| options.is_highlighted(index))
+        .peekable();
+    if highlighted.peek().is_some() {
+        out.write_str("    classDef highlight fill:#ffd54f,stroke:#e65100\n")?;
+        out.write_str("    class ")?;
+        for (i, index) in highlighted.enumerate() {
+            if i > 0 {
+                out.write_char(',')?;
+            }
+            write!(out, "n{}", index.to_flattened())?;
+        }
+        out.write_str(" highlight\n")?;
+    }
+
+    // Mermaid addresses links by declaration order, and the link into the node at
+    // flattened index `i` is declared `i - 1`th.
+    let mut edges = LevelOrder::<N>::new(tree.len())
+        .skip(1)
+        .filter(|&index| options.is_edge_highlighted(index))
+        .peekable();
+    if edges.peek().is_some() {
+        out.write_str("    linkStyle ")?;
+        for (i, index) in edges.enumerate() {
+            if i > 0 {
+                out.write_char(',')?;
+            }
+            write!(out, "{}", index.to_flattened() - 1)?;
+        }
+        out.write_str(" stroke:#e65100,stroke-width:2px\n")?;
+    }
+    Ok(())
+}
+
+struct Escape<'a, W: ?Sized> {
+    inner: &'a mut W,
+    escape: fn(char) -> Option<&'static str>,
+}
+
+impl<'a, W: Write + ?Sized> Escape<'a, W> {
+    fn dot(inner: &'a mut W) -> Self {
+        let escape = |c| match c {
+            '"' => Some("\\\""),
+            '\\' => Some("\\\\"),
+            '\n' => Some("\\n"),
+            _ => None,
+        };
+        Self { inner, escape }
+    }
+
+    fn mermaid(inner: &'a mut W) -> Self {
+        let escape = |c| match c {
+            '"' => Some("#quot;"),
+            '\n' => Some("<br>"),
+            _ => None,
+        };
+        Self { inner, escape }
+    }
+}
+
+impl<W: Write + ?Sized> Write for Escape<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match (self.escape)(c) {
+                Some(escaped) => self.inner.write_str(escaped)?,
+                None => self.inner.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
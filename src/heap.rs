@@ -0,0 +1,168 @@
+#[cfg(feature = "alloc")]
+pub use self::dary_heap::{DaryHeap, PeekMut};
+#[cfg(feature = "alloc")]
+pub use self::indexed_heap::IndexedHeap;
+pub use self::sort::{
+    heap_sort, heap_sort_by, heap_sort_by_key, partial_sort, partial_sort_by, select_nth_smallest,
+    select_nth_smallest_by,
+};
+
+use crate::{Index, SliceTree};
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+mod dary_heap;
+#[cfg(feature = "alloc")]
+pub mod indexed_heap;
+mod sort;
+
+pub trait Compare<T: ?Sized> {
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+impl<T: ?Sized, F> Compare<T> for F
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaxComparator;
+
+impl<T: Ord + ?Sized> Compare<T> for MaxComparator {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MinComparator;
+
+impl<T: Ord + ?Sized> Compare<T> for MinComparator {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+pub fn sift_up<const N: usize, T, C>(
+    tree: &mut SliceTree<N, T>,
+    index: Index<N>,
+    compare: &C,
+) -> Option<Index<N>>
+where
+    C: Compare<T> + ?Sized,
+{
+    let nodes: &mut [T] = tree.into();
+    let index = index.to_flattened();
+    if index >= nodes.len() {
+        return None;
+    }
+    let index = sift_up_flattened::<N, T, C>(nodes, index, compare);
+    Some(Index::from_flattened(index))
+}
+
+pub fn sift_down<const N: usize, T, C>(
+    tree: &mut SliceTree<N, T>,
+    index: Index<N>,
+    compare: &C,
+) -> Option<Index<N>>
+where
+    C: Compare<T> + ?Sized,
+{
+    let nodes: &mut [T] = tree.into();
+    let index = index.to_flattened();
+    if index >= nodes.len() {
+        return None;
+    }
+    let index = sift_down_flattened::<N, T, C>(nodes, index, compare);
+    Some(Index::from_flattened(index))
+}
+
+pub fn heapify<const N: usize, T, C>(tree: &mut SliceTree<N, T>, compare: &C)
+where
+    C: Compare<T> + ?Sized,
+{
+    heapify_flattened::<N, T, C>(tree.into(), compare);
+}
+
+pub fn is_heap<const N: usize, T, C>(tree: &SliceTree<N, T>, compare: &C) -> bool
+where
+    C: Compare<T> + ?Sized,
+{
+    let nodes: &[T] = tree.into();
+    (1..nodes.len()).all(|index| {
+        let parent = (index - 1) / N;
+        compare.compare(&nodes[parent], &nodes[index]) != Ordering::Less
+    })
+}
+
+pub(crate) fn sift_up_flattened<const N: usize, T, C>(
+    nodes: &mut [T],
+    mut index: usize,
+    compare: &C,
+) -> usize
+where
+    C: Compare<T> + ?Sized,
+{
+    while index > 0 {
+        let parent = (index - 1) / N;
+        if compare.compare(&nodes[parent], &nodes[index]) != Ordering::Less {
+            break;
+        }
+        nodes.swap(parent, index);
+        index = parent;
+    }
+    index
+}
+
+pub(crate) fn sift_down_flattened<const N: usize, T, C>(
+    nodes: &mut [T],
+    mut index: usize,
+    compare: &C,
+) -> usize
+where
+    C: Compare<T> + ?Sized,
+{
+    while let Some(child) = greatest_child::<N, T, C>(nodes, index, compare) {
+        if compare.compare(&nodes[index], &nodes[child]) != Ordering::Less {
+            break;
+        }
+        nodes.swap(index, child);
+        index = child;
+    }
+    index
+}
+
+pub(crate) fn heapify_flattened<const N: usize, T, C>(nodes: &mut [T], compare: &C)
+where
+    C: Compare<T> + ?Sized,
+{
+    if nodes.len() < 2 {
+        return;
+    }
+    let last_parent = (nodes.len() - 2) / N;
+    for index in (0..=last_parent).rev() {
+        sift_down_flattened::<N, T, C>(nodes, index, compare);
+    }
+}
+
+fn greatest_child<const N: usize, T, C>(nodes: &[T], index: usize, compare: &C) -> Option<usize>
+where
+    C: Compare<T> + ?Sized,
+{
+    let first = index.checked_mul(N)?.checked_add(1)?;
+    if first >= nodes.len() {
+        return None;
+    }
+    let last = first.saturating_add(N - 1).min(nodes.len() - 1);
+    let mut greatest = first;
+    for child in first + 1..=last {
+        if compare.compare(&nodes[greatest], &nodes[child]) == Ordering::Less {
+            greatest = child;
+        }
+    }
+    Some(greatest)
+}
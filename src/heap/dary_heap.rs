@@ -0,0 +1,227 @@
+use super::sort::sort_heap_flattened;
+use super::{Compare, MaxComparator, heapify_flattened, sift_down_flattened, sift_up_flattened};
+use crate::{SliceTree, VecTree};
+use alloc::vec::{self, Drain, Vec};
+use core::fmt;
+use core::mem;
+use core::ops::{Deref, DerefMut};
+use core::slice::Iter;
+
+#[derive(Debug, Clone)]
+pub struct DaryHeap<const N: usize, T, C = MaxComparator> {
+    nodes: Vec<T>,
+    compare: C,
+}
+
+impl<const N: usize, T, C: Default> DaryHeap<N, T, C> {
+    pub fn new() -> Self {
+        Self::with_comparator(C::default())
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_comparator(capacity, C::default())
+    }
+}
+
+impl<const N: usize, T, C> DaryHeap<N, T, C> {
+    pub const fn with_comparator(compare: C) -> Self {
+        let nodes = Vec::new();
+        Self { nodes, compare }
+    }
+
+    pub fn with_capacity_and_comparator(capacity: usize, compare: C) -> Self {
+        let nodes = Vec::with_capacity(capacity);
+        Self { nodes, compare }
+    }
+
+    pub fn comparator(&self) -> &C {
+        &self.compare
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.nodes.capacity()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.nodes.first()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.nodes.iter()
+    }
+
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        self.nodes.drain(..)
+    }
+
+    pub fn as_slice_tree(&self) -> &SliceTree<N, T> {
+        self.nodes.as_slice().into()
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.nodes
+    }
+
+    pub fn into_tree(self) -> VecTree<N, T> {
+        VecTree::from(self.nodes)
+    }
+}
+
+impl<const N: usize, T, C: Compare<T>> DaryHeap<N, T, C> {
+    pub fn from_vec_with_comparator(nodes: Vec<T>, compare: C) -> Self {
+        let mut heap = Self { nodes, compare };
+        heap.rebuild();
+        heap
+    }
+
+    pub fn push(&mut self, node: T) {
+        self.nodes.push(node);
+        let last = self.nodes.len() - 1;
+        sift_up_flattened::<N, T, C>(&mut self.nodes, last, &self.compare);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let node = self.nodes.pop()?;
+        if self.nodes.is_empty() {
+            return Some(node);
+        }
+        let root = mem::replace(&mut self.nodes[0], node);
+        sift_down_flattened::<N, T, C>(&mut self.nodes, 0, &self.compare);
+        Some(root)
+    }
+
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, N, T, C>> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(PeekMut { heap: self })
+    }
+
+    pub fn append(&mut self, other: &mut Self) {
+        self.nodes.append(&mut other.nodes);
+        self.rebuild();
+    }
+
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        sort_heap_flattened::<N, T, C>(&mut self.nodes, &self.compare);
+        self.nodes
+    }
+
+    fn rebuild(&mut self) {
+        heapify_flattened::<N, T, C>(&mut self.nodes, &self.compare);
+    }
+}
+
+impl<const N: usize, T, C: Default> Default for DaryHeap<N, T, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T, C: Compare<T> + Default> From<Vec<T>> for DaryHeap<N, T, C> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from_vec_with_comparator(value, C::default())
+    }
+}
+
+impl<const N: usize, T, C: Compare<T> + Default> From<VecTree<N, T>> for DaryHeap<N, T, C> {
+    fn from(value: VecTree<N, T>) -> Self {
+        Self::from(value.into_vec())
+    }
+}
+
+impl<const N: usize, T, C> From<DaryHeap<N, T, C>> for Vec<T> {
+    fn from(value: DaryHeap<N, T, C>) -> Self {
+        value.into_vec()
+    }
+}
+
+impl<const N: usize, T, C: Compare<T> + Default> FromIterator<T> for DaryHeap<N, T, C> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(Vec::from_iter(iter))
+    }
+}
+
+impl<const N: usize, T, C: Compare<T>> Extend<T> for DaryHeap<N, T, C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for node in iter {
+            self.push(node);
+        }
+    }
+}
+
+impl<const N: usize, T, C> IntoIterator for DaryHeap<N, T, C> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.nodes.into_iter()
+    }
+}
+
+impl<'a, const N: usize, T, C> IntoIterator for &'a DaryHeap<N, T, C> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct PeekMut<'a, const N: usize, T, C: Compare<T>> {
+    heap: &'a mut DaryHeap<N, T, C>,
+}
+
+impl<const N: usize, T, C: Compare<T>> PeekMut<'_, N, T, C> {
+    pub fn pop(this: Self) -> T {
+        let node = unsafe { this.heap.pop().unwrap_unchecked() };
+        mem::forget(this);
+        node
+    }
+}
+
+impl<const N: usize, T: fmt::Debug, C: Compare<T>> fmt::Debug for PeekMut<'_, N, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PeekMut").field(self.deref()).finish()
+    }
+}
+
+impl<const N: usize, T, C: Compare<T>> Deref for PeekMut<'_, N, T, C> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.heap.nodes.get_unchecked(0) }
+    }
+}
+
+impl<const N: usize, T, C: Compare<T>> DerefMut for PeekMut<'_, N, T, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { self.heap.nodes.get_unchecked_mut(0) }
+    }
+}
+
+impl<const N: usize, T, C: Compare<T>> Drop for PeekMut<'_, N, T, C> {
+    fn drop(&mut self) {
+        sift_down_flattened::<N, T, C>(&mut self.heap.nodes, 0, &self.heap.compare);
+    }
+}
@@ -0,0 +1,273 @@
+use super::{Compare, MaxComparator};
+use crate::{CompleteTree, Index, SliceTree, VecTree};
+use alloc::collections::BTreeMap;
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::mem;
+use core::slice;
+
+#[derive(Debug, Clone)]
+pub struct IndexedHeap<const N: usize, T, C = MaxComparator> {
+    nodes: VecTree<N, (usize, T)>,
+    positions: BTreeMap<usize, usize>,
+    compare: C,
+}
+
+impl<const N: usize, T, C: Default> IndexedHeap<N, T, C> {
+    pub fn new() -> Self {
+        Self::with_comparator(C::default())
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_comparator(capacity, C::default())
+    }
+}
+
+impl<const N: usize, T, C> IndexedHeap<N, T, C> {
+    pub const fn with_comparator(compare: C) -> Self {
+        let nodes = VecTree::new();
+        let positions = BTreeMap::new();
+        Self {
+            nodes,
+            positions,
+            compare,
+        }
+    }
+
+    pub fn with_capacity_and_comparator(capacity: usize, compare: C) -> Self {
+        let nodes = VecTree::with_capacity(capacity);
+        let positions = BTreeMap::new();
+        Self {
+            nodes,
+            positions,
+            compare,
+        }
+    }
+
+    pub fn comparator(&self) -> &C {
+        &self.compare
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.positions.clear();
+    }
+
+    pub fn contains(&self, key: usize) -> bool {
+        self.positions.contains_key(&key)
+    }
+
+    pub fn position(&self, key: usize) -> Option<Index<N>> {
+        let index = self.positions.get(&key)?;
+        Some(Index::from_flattened(*index))
+    }
+
+    pub fn get(&self, key: usize) -> Option<&T> {
+        let index = self.position(key)?;
+        Some(&self.nodes[index].1)
+    }
+
+    pub fn peek(&self) -> Option<(usize, &T)> {
+        let (key, priority) = self.nodes.root()?;
+        Some((*key, priority))
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter(self.nodes.iter())
+    }
+
+    pub fn as_slice_tree(&self) -> &SliceTree<N, (usize, T)> {
+        self.nodes.as_slice_tree()
+    }
+
+    // Every move of a node goes through here, so `positions` never disagrees
+    // with the tree.
+    fn swap(&mut self, index_a: Index<N>, index_b: Index<N>) {
+        if self.nodes.swap(index_a, index_b).is_none() {
+            return;
+        }
+        for index in [index_a, index_b] {
+            let (key, _) = self.nodes[index];
+            self.positions.insert(key, index.to_flattened());
+        }
+    }
+}
+
+impl<const N: usize, T, C: Compare<T>> IndexedHeap<N, T, C> {
+    pub fn push(&mut self, key: usize, priority: T) -> Option<T> {
+        if self.contains(key) {
+            return self.change_priority(key, priority);
+        }
+        let index = self.nodes.len();
+        self.nodes.push((key, priority));
+        self.positions.insert(key, index);
+        self.sift_up(Index::from_flattened(index));
+        None
+    }
+
+    pub fn pop(&mut self) -> Option<(usize, T)> {
+        let last = Index::from_flattened(self.nodes.len().checked_sub(1)?);
+        self.swap(Index::root(), last);
+        let (key, priority) = self.nodes.pop()?;
+        self.positions.remove(&key);
+        if !self.is_empty() {
+            self.sift_down(Index::root());
+        }
+        Some((key, priority))
+    }
+
+    pub fn change_priority(&mut self, key: usize, priority: T) -> Option<T> {
+        let index = self.position(key)?;
+        let old = mem::replace(&mut self.nodes[index].1, priority);
+        let index = self.sift_up(index);
+        self.sift_down(index);
+        Some(old)
+    }
+
+    /// Replaces the priority of `key` with one that compares no less, as when
+    /// a shorter path is found in Dijkstra's algorithm with a
+    /// [`MinComparator`](super::MinComparator).
+    ///
+    /// It is a logic error for `priority` to compare less than the priority it
+    /// replaces; the heap order is then unspecified, but stays memory safe.
+    pub fn increase_priority(&mut self, key: usize, priority: T) -> Option<T> {
+        let index = self.position(key)?;
+        let old = mem::replace(&mut self.nodes[index].1, priority);
+        self.sift_up(index);
+        Some(old)
+    }
+
+    /// Replaces the priority of `key` with one that compares no greater.
+    ///
+    /// It is a logic error for `priority` to compare greater than the priority
+    /// it replaces; the heap order is then unspecified, but stays memory safe.
+    pub fn decrease_priority(&mut self, key: usize, priority: T) -> Option<T> {
+        let index = self.position(key)?;
+        let old = mem::replace(&mut self.nodes[index].1, priority);
+        self.sift_down(index);
+        Some(old)
+    }
+
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let index = self.position(key)?;
+        let last = Index::from_flattened(self.nodes.len() - 1);
+        self.swap(index, last);
+        let (_, priority) = self.nodes.pop()?;
+        self.positions.remove(&key);
+        if index != last {
+            let index = self.sift_up(index);
+            self.sift_down(index);
+        }
+        Some(priority)
+    }
+
+    fn sift_up(&mut self, mut index: Index<N>) -> Index<N> {
+        while let Some(parent) = index.parent() {
+            if self.compare_nodes(parent, index) != Ordering::Less {
+                break;
+            }
+            self.swap(parent, index);
+            index = parent;
+        }
+        index
+    }
+
+    fn sift_down(&mut self, mut index: Index<N>) -> Index<N> {
+        while let Some(child) = self.greatest_child(index) {
+            if self.compare_nodes(index, child) != Ordering::Less {
+                break;
+            }
+            self.swap(index, child);
+            index = child;
+        }
+        index
+    }
+
+    fn greatest_child(&self, index: Index<N>) -> Option<Index<N>> {
+        let mut children = index.iter_children().cap(self.nodes.len());
+        let first = children.next()?;
+        let greatest = children.fold(first, |greatest, child| {
+            match self.compare_nodes(greatest, child) {
+                Ordering::Less => child,
+                _ => greatest,
+            }
+        });
+        Some(greatest)
+    }
+
+    fn compare_nodes(&self, index_a: Index<N>, index_b: Index<N>) -> Ordering {
+        let (_, a) = &self.nodes[index_a];
+        let (_, b) = &self.nodes[index_b];
+        self.compare.compare(a, b)
+    }
+}
+
+impl<const N: usize, T, C: Default> Default for IndexedHeap<N, T, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T, C: Compare<T>> Extend<(usize, T)> for IndexedHeap<N, T, C> {
+    fn extend<I: IntoIterator<Item = (usize, T)>>(&mut self, iter: I) {
+        for (key, priority) in iter {
+            self.push(key, priority);
+        }
+    }
+}
+
+impl<const N: usize, T, C: Compare<T> + Default> FromIterator<(usize, T)> for IndexedHeap<N, T, C> {
+    fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
+impl<'a, const N: usize, T, C> IntoIterator for &'a IndexedHeap<N, T, C> {
+    type Item = (usize, &'a T);
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T>(slice::Iter<'a, (usize, T)>);
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, priority) = self.0.next()?;
+        Some((*key, priority))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (key, priority) = self.0.next_back()?;
+        Some((*key, priority))
+    }
+}
+
+impl<T> FusedIterator for Iter<'_, T> {}
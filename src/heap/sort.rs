@@ -0,0 +1,115 @@
+use super::{Compare, MaxComparator, heapify_flattened, sift_down_flattened};
+use crate::SliceTree;
+use core::cmp::Ordering;
+
+pub fn heap_sort<const N: usize, T: Ord>(tree: &mut SliceTree<N, T>) {
+    heap_sort_flattened::<N, T, _>(tree.into(), &MaxComparator);
+}
+
+pub fn heap_sort_by<const N: usize, T, F>(tree: &mut SliceTree<N, T>, compare: F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    heap_sort_flattened::<N, T, _>(tree.into(), &compare);
+}
+
+pub fn heap_sort_by_key<const N: usize, T, K, F>(tree: &mut SliceTree<N, T>, f: F)
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    let compare = |a: &T, b: &T| f(a).cmp(&f(b));
+    heap_sort_flattened::<N, T, _>(tree.into(), &compare);
+}
+
+pub fn partial_sort<const N: usize, T: Ord>(tree: &mut SliceTree<N, T>, k: usize) {
+    partial_sort_flattened::<N, T, _>(tree.into(), k, &MaxComparator);
+}
+
+pub fn partial_sort_by<const N: usize, T, F>(tree: &mut SliceTree<N, T>, k: usize, compare: F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    partial_sort_flattened::<N, T, _>(tree.into(), k, &compare);
+}
+
+pub fn select_nth_smallest<const N: usize, T: Ord>(
+    tree: &mut SliceTree<N, T>,
+    n: usize,
+) -> Option<&mut T> {
+    select_nth_smallest_flattened::<N, T, _>(tree.into(), n, &MaxComparator)
+}
+
+pub fn select_nth_smallest_by<const N: usize, T, F>(
+    tree: &mut SliceTree<N, T>,
+    n: usize,
+    compare: F,
+) -> Option<&mut T>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    select_nth_smallest_flattened::<N, T, _>(tree.into(), n, &compare)
+}
+
+pub(crate) fn heap_sort_flattened<const N: usize, T, C>(nodes: &mut [T], compare: &C)
+where
+    C: Compare<T> + ?Sized,
+{
+    heapify_flattened::<N, T, C>(nodes, compare);
+    sort_heap_flattened::<N, T, C>(nodes, compare);
+}
+
+pub(crate) fn sort_heap_flattened<const N: usize, T, C>(nodes: &mut [T], compare: &C)
+where
+    C: Compare<T> + ?Sized,
+{
+    let mut end = nodes.len();
+    while end > 1 {
+        end -= 1;
+        nodes.swap(0, end);
+        sift_down_flattened::<N, T, C>(&mut nodes[..end], 0, compare);
+    }
+}
+
+fn partial_sort_flattened<const N: usize, T, C>(nodes: &mut [T], k: usize, compare: &C)
+where
+    C: Compare<T> + ?Sized,
+{
+    let k = k.min(nodes.len());
+    if k == 0 {
+        return;
+    }
+    retain_smallest::<N, T, C>(nodes, k, compare);
+    sort_heap_flattened::<N, T, C>(&mut nodes[..k], compare);
+}
+
+fn select_nth_smallest_flattened<'a, const N: usize, T, C>(
+    nodes: &'a mut [T],
+    n: usize,
+    compare: &C,
+) -> Option<&'a mut T>
+where
+    C: Compare<T> + ?Sized,
+{
+    if n >= nodes.len() {
+        return None;
+    }
+    let k = n + 1;
+    retain_smallest::<N, T, C>(nodes, k, compare);
+    nodes.swap(0, n);
+    Some(&mut nodes[n])
+}
+
+fn retain_smallest<const N: usize, T, C>(nodes: &mut [T], k: usize, compare: &C)
+where
+    C: Compare<T> + ?Sized,
+{
+    let (heap, rest) = nodes.split_at_mut(k);
+    heapify_flattened::<N, T, C>(heap, compare);
+    for node in rest {
+        if compare.compare(node, &heap[0]) == Ordering::Less {
+            core::mem::swap(node, &mut heap[0]);
+            sift_down_flattened::<N, T, C>(heap, 0, compare);
+        }
+    }
+}
@@ -1,8 +1,17 @@
+pub use self::path::{Ancestors, PathBetween, PathFromRoot};
+pub use self::subtree::SubtreeLevels;
+
+pub(crate) use self::subtree::next_level;
+
+use crate::Error;
 use core::iter::FusedIterator;
 use core::ops::RangeInclusive;
 
 pub mod traverse;
 
+mod path;
+mod subtree;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Index<const N: usize> {
     depth: usize,
@@ -31,6 +40,17 @@ impl<const N: usize> Index<N> {
         }
     }
 
+    pub const fn try_new(depth: usize, offset: usize) -> Result<Self, Error> {
+        if depth > Self::MAX.depth {
+            let max_depth = Self::MAX.depth;
+            return Err(Error::DepthOverflow { depth, max_depth });
+        }
+        match Self::new(depth, offset) {
+            Some(index) => Ok(index),
+            None => Err(Error::OffsetOutOfLevel { depth, offset }),
+        }
+    }
+
     pub const fn root() -> Self {
         Self::MIN
     }
@@ -45,6 +65,92 @@ impl<const N: usize> Index<N> {
         Some(Self { depth, offset })
     }
 
+    pub fn ancestors(&self) -> Ancestors<N> {
+        Ancestors::new(*self)
+    }
+
+    pub fn path_from_root(&self) -> PathFromRoot<N> {
+        PathFromRoot::new(*self)
+    }
+
+    pub const fn ancestor_at_depth(&self, depth: usize) -> Option<Self> {
+        if depth > self.depth {
+            return None;
+        }
+
+        let height = self.depth - depth;
+        let offset = match N {
+            2 => match self.offset.checked_shr(height as u32) {
+                Some(offset) => offset,
+                None => 0,
+            },
+
+            _ => match N.checked_pow(height as u32) {
+                Some(width) => self.offset / width,
+                None => 0,
+            },
+        };
+        Some(Self { depth, offset })
+    }
+
+    pub const fn is_ancestor_of(&self, other: &Self) -> bool {
+        if self.depth >= other.depth {
+            return false;
+        }
+        match other.ancestor_at_depth(self.depth) {
+            Some(ancestor) => ancestor.offset == self.offset,
+            None => false,
+        }
+    }
+
+    pub const fn lowest_common_ancestor(&self, other: &Self) -> Self {
+        let depth = if self.depth < other.depth {
+            self.depth
+        } else {
+            other.depth
+        };
+        let (Some(a), Some(b)) = (
+            self.ancestor_at_depth(depth),
+            other.ancestor_at_depth(depth),
+        ) else {
+            unreachable!()
+        };
+
+        match N {
+            2 => {
+                let height = (usize::BITS - (a.offset ^ b.offset).leading_zeros()) as usize;
+                let depth = depth - height;
+                let offset = match a.offset.checked_shr(height as u32) {
+                    Some(offset) => offset,
+                    None => 0,
+                };
+                Self { depth, offset }
+            }
+
+            _ => {
+                let mut depth = depth;
+                let mut offset_a = a.offset;
+                let mut offset_b = b.offset;
+                while offset_a != offset_b {
+                    offset_a /= N;
+                    offset_b /= N;
+                    depth -= 1;
+                }
+                let offset = offset_a;
+                Self { depth, offset }
+            }
+        }
+    }
+
+    pub const fn distance(&self, other: &Self) -> usize {
+        let ancestor = self.lowest_common_ancestor(other);
+        (self.depth - ancestor.depth) + (other.depth - ancestor.depth)
+    }
+
+    pub fn path_between(&self, other: &Self) -> PathBetween<N> {
+        PathBetween::new(*self, *other)
+    }
+
     pub const fn first_child(&self) -> Option<Self> {
         self.child(0)
     }
@@ -72,6 +178,76 @@ impl<const N: usize> Index<N> {
         Some(Self { depth, offset })
     }
 
+    pub const fn sibling_number(&self) -> usize {
+        if self.depth == Self::MIN.depth {
+            return 0;
+        }
+        self.offset % N
+    }
+
+    pub const fn next_sibling(&self) -> Option<Self> {
+        if self.depth == Self::MIN.depth || self.offset % N == N - 1 {
+            return None;
+        }
+        Self::new(self.depth, self.offset + 1)
+    }
+
+    pub const fn prev_sibling(&self) -> Option<Self> {
+        if self.depth == Self::MIN.depth || self.offset.is_multiple_of(N) {
+            return None;
+        }
+        let depth = self.depth;
+        let offset = self.offset - 1;
+        Some(Self { depth, offset })
+    }
+
+    pub fn iter_siblings(&self) -> IndexRange<N> {
+        match self.parent() {
+            Some(parent) => parent.iter_children(),
+            None => IndexRange::root(),
+        }
+    }
+
+    pub const fn next_in_level(&self) -> Option<Self> {
+        match self.offset.checked_add(1) {
+            Some(offset) => Self::new(self.depth, offset),
+            None => None,
+        }
+    }
+
+    pub const fn prev_in_level(&self) -> Option<Self> {
+        match self.offset.checked_sub(1) {
+            Some(offset) => {
+                let depth = self.depth;
+                Some(Self { depth, offset })
+            }
+            None => None,
+        }
+    }
+
+    pub fn subtree_levels(&self, tree_len: usize) -> SubtreeLevels<N> {
+        SubtreeLevels::new(self.to_flattened(), tree_len)
+    }
+
+    pub const fn try_child(&self, n: usize) -> Result<Self, Error> {
+        if n >= N {
+            return Err(Error::ChildOutOfRange { n, arity: N });
+        }
+        if self.depth == Self::MAX.depth {
+            let depth = self.depth.saturating_add(1);
+            let max_depth = Self::MAX.depth;
+            return Err(Error::DepthOverflow { depth, max_depth });
+        }
+        match self.child(n) {
+            Some(child) => Ok(child),
+            None => {
+                let depth = self.depth + 1;
+                let offset = N.saturating_mul(self.offset).saturating_add(n);
+                Err(Error::OffsetOutOfLevel { depth, offset })
+            }
+        }
+    }
+
     pub fn iter_children(&self) -> IndexRange<N> {
         if self.depth == Self::MAX.depth {
             return IndexRange::empty();
@@ -98,65 +274,14 @@ impl<const N: usize> Index<N> {
     pub const fn from_flattened(index: usize) -> Self {
         const { assert!(N != 0) }
 
-        match N {
-            1 => {
-                let depth = index;
-                let offset = 0;
-                Self { depth, offset }
-            }
-
-            2 => {
-                if index == usize::MAX {
-                    let depth = usize::BITS as usize;
-                    let offset = 0;
-                    return Self { depth, offset };
-                }
-                let next = index + 1;
-                let depth = (const { usize::BITS - 1 } - next.leading_zeros()) as usize;
-                let offset = next - (1 << depth);
-                Self { depth, offset }
-            }
-
-            _ => {
-                let mut count: usize = 0;
-                let mut depth: usize = 0;
-                while let Some(width) = N.checked_pow(depth as u32)
-                    && let Some(next_count) = count.checked_add(width)
-                    && index >= next_count
-                {
-                    count = next_count;
-                    depth += 1;
-                }
-                let offset = index - count;
-                Self { depth, offset }
-            }
-        }
+        let (depth, offset) = split_flattened(N, index);
+        Self { depth, offset }
     }
 
     pub const fn to_flattened(self) -> usize {
-        match N {
-            1 => self.depth,
-
-            2 => {
-                if self.depth == usize::BITS as usize {
-                    usize::MAX
-                } else {
-                    (1 << self.depth) - 1 + self.offset
-                }
-            }
-
-            _ => {
-                // `((N.pow(depth) - 1) / (N - 1)) + offset` may overflow for large `N`.
-
-                let mut count = 0;
-                let mut depth = 0;
-                while depth < self.depth {
-                    let width = N.pow(depth as u32);
-                    count += width;
-                    depth += 1;
-                }
-                count + self.offset
-            }
+        match level_start(N, self.depth) {
+            Some(start) => start + self.offset,
+            None => unreachable!(),
         }
     }
 }
@@ -171,6 +296,52 @@ impl Index<2> {
     }
 }
 
+/// Implemented by [`Index`] and [`DynIndex`](crate::DynIndex), so node
+/// traversals can be shared between them.
+pub trait TreeIndex: Copy + sealed::TreeIndex {}
+
+pub(crate) mod sealed {
+    use crate::index::traverse::{MaxDepth, Traversal};
+
+    pub trait TreeIndex {
+        type PreOrder: Traversal<Item = Self> + MaxDepth;
+
+        type PostOrder: Traversal<Item = Self> + MaxDepth;
+
+        fn arity(&self) -> usize;
+
+        fn to_flattened(&self) -> usize;
+
+        fn pre_order_from(self, tree_len: usize) -> Self::PreOrder;
+
+        fn post_order_from(self, tree_len: usize) -> Self::PostOrder;
+    }
+}
+
+impl<const N: usize> TreeIndex for Index<N> {}
+
+impl<const N: usize> sealed::TreeIndex for Index<N> {
+    type PreOrder = traverse::PreOrder<N>;
+
+    type PostOrder = traverse::PostOrder<N>;
+
+    fn arity(&self) -> usize {
+        N
+    }
+
+    fn to_flattened(&self) -> usize {
+        Index::to_flattened(*self)
+    }
+
+    fn pre_order_from(self, tree_len: usize) -> Self::PreOrder {
+        traverse::PreOrder::new_from(self, tree_len)
+    }
+
+    fn post_order_from(self, tree_len: usize) -> Self::PostOrder {
+        traverse::PostOrder::new_from(self, tree_len)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct IndexRange<const N: usize>(RangeInclusive<usize>);
 
@@ -225,7 +396,7 @@ impl<const N: usize> IndexRange<N> {
         Self::from_flattened(start..=end)
     }
 
-    const fn from_flattened(range: RangeInclusive<usize>) -> Self {
+    pub(crate) const fn from_flattened(range: RangeInclusive<usize>) -> Self {
         const { assert!(N != 0) }
 
         debug_assert!(
@@ -273,3 +444,64 @@ impl<const N: usize> DoubleEndedIterator for IndexRange<N> {
 }
 
 impl<const N: usize> FusedIterator for IndexRange<N> {}
+
+pub(crate) const fn split_flattened(arity: usize, index: usize) -> (usize, usize) {
+    match arity {
+        1 => (index, 0),
+
+        2 => {
+            if index == usize::MAX {
+                return (usize::BITS as usize, 0);
+            }
+            let next = index + 1;
+            let depth = (const { usize::BITS - 1 } - next.leading_zeros()) as usize;
+            (depth, next - (1 << depth))
+        }
+
+        _ => {
+            let mut count: usize = 0;
+            let mut depth: usize = 0;
+            while let Some(width) = arity.checked_pow(depth as u32)
+                && let Some(next_count) = count.checked_add(width)
+                && index >= next_count
+            {
+                count = next_count;
+                depth += 1;
+            }
+            (depth, index - count)
+        }
+    }
+}
+
+pub(crate) const fn level_start(arity: usize, depth: usize) -> Option<usize> {
+    match arity {
+        1 => Some(depth),
+
+        2 => {
+            if depth > usize::BITS as usize {
+                None
+            } else if depth == 0 {
+                Some(0)
+            } else {
+                Some(usize::MAX >> (usize::BITS as usize - depth))
+            }
+        }
+
+        _ => {
+            // `(arity.pow(depth) - 1) / (arity - 1)` may overflow before the division.
+            let mut count: usize = 0;
+            let mut level = 0;
+            while level < depth {
+                let Some(width) = arity.checked_pow(level as u32) else {
+                    return None;
+                };
+                let Some(next_count) = count.checked_add(width) else {
+                    return None;
+                };
+                count = next_count;
+                level += 1;
+            }
+            Some(count)
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use super::Index;
+use super::traverse::{Traversal, sealed};
+use core::iter::FusedIterator;
+use core::ops::{Range, RangeInclusive};
+
+#[derive(Debug, Clone)]
+pub struct Ancestors<const N: usize> {
+    index: Index<N>,
+    depths: Range<usize>,
+}
+
+impl<const N: usize> Ancestors<N> {
+    pub(crate) fn new(index: Index<N>) -> Self {
+        let depths = 0..index.depth();
+        Self { index, depths }
+    }
+
+    fn ancestor(&self, depth: usize) -> Index<N> {
+        let Some(ancestor) = self.index.ancestor_at_depth(depth) else {
+            unreachable!()
+        };
+        ancestor
+    }
+}
+
+impl<const N: usize> Iterator for Ancestors<N> {
+    type Item = Index<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let depth = self.depths.next_back()?;
+        Some(self.ancestor(depth))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.depths.size_hint()
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for Ancestors<N> {
+    fn len(&self) -> usize {
+        self.depths.len()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for Ancestors<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let depth = self.depths.next()?;
+        Some(self.ancestor(depth))
+    }
+}
+
+impl<const N: usize> FusedIterator for Ancestors<N> {}
+
+impl<const N: usize> Traversal for Ancestors<N> {}
+
+impl<const N: usize> sealed::Traversal for Ancestors<N> {
+    fn next_flattened(&mut self) -> Option<usize> {
+        let index = self.next()?;
+        Some(index.to_flattened())
+    }
+
+    fn next_back_flattened(&mut self) -> Option<usize> {
+        let index = self.next_back()?;
+        Some(index.to_flattened())
+    }
+
+    fn index(&self, index: usize) -> Self::Item {
+        Index::from_flattened(index)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PathFromRoot<const N: usize> {
+    index: Index<N>,
+    depths: Range<usize>,
+}
+
+impl<const N: usize> PathFromRoot<N> {
+    pub(crate) fn new(index: Index<N>) -> Self {
+        let depths = 0..index.depth();
+        Self { index, depths }
+    }
+
+    fn child_number(&self, depth: usize) -> usize {
+        let Some(ancestor) = self.index.ancestor_at_depth(depth + 1) else {
+            unreachable!()
+        };
+        ancestor.offset() % N
+    }
+}
+
+impl<const N: usize> Iterator for PathFromRoot<N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let depth = self.depths.next()?;
+        Some(self.child_number(depth))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.depths.size_hint()
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for PathFromRoot<N> {
+    fn len(&self) -> usize {
+        self.depths.len()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for PathFromRoot<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let depth = self.depths.next_back()?;
+        Some(self.child_number(depth))
+    }
+}
+
+impl<const N: usize> FusedIterator for PathFromRoot<N> {}
+
+#[derive(Debug, Clone)]
+pub struct PathBetween<const N: usize> {
+    from: Index<N>,
+    to: Index<N>,
+    turn: usize,
+    steps: RangeInclusive<usize>,
+}
+
+impl<const N: usize> PathBetween<N> {
+    pub(crate) fn new(from: Index<N>, to: Index<N>) -> Self {
+        let ancestor = from.lowest_common_ancestor(&to);
+        let turn = from.depth() - ancestor.depth();
+        let steps = 0..=turn + (to.depth() - ancestor.depth());
+        Self {
+            from,
+            to,
+            turn,
+            steps,
+        }
+    }
+
+    fn index(&self, step: usize) -> Index<N> {
+        let ancestor = if step <= self.turn {
+            self.from.ancestor_at_depth(self.from.depth() - step)
+        } else {
+            let depth = self.from.depth() - self.turn + (step - self.turn);
+            self.to.ancestor_at_depth(depth)
+        };
+        let Some(ancestor) = ancestor else {
+            unreachable!()
+        };
+        ancestor
+    }
+}
+
+impl<const N: usize> Iterator for PathBetween<N> {
+    type Item = Index<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let step = self.steps.next()?;
+        Some(self.index(step))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.steps.size_hint()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for PathBetween<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let step = self.steps.next_back()?;
+        Some(self.index(step))
+    }
+}
+
+impl<const N: usize> FusedIterator for PathBetween<N> {}
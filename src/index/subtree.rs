@@ -0,0 +1,52 @@
+use super::IndexRange;
+use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
+
+#[derive(Debug, Clone)]
+pub struct SubtreeLevels<const N: usize> {
+    level: Option<(usize, usize)>,
+    tree_len: usize,
+}
+
+impl<const N: usize> SubtreeLevels<N> {
+    pub(crate) fn new(root: usize, tree_len: usize) -> Self {
+        let level = Some((root, root));
+        Self { level, tree_len }
+    }
+}
+
+impl<const N: usize> Iterator for SubtreeLevels<N> {
+    type Item = IndexRange<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range = next_level(N, &mut self.level, self.tree_len)?;
+        Some(IndexRange::from_flattened(range))
+    }
+}
+
+impl<const N: usize> FusedIterator for SubtreeLevels<N> {}
+
+pub(crate) fn next_level(
+    arity: usize,
+    level: &mut Option<(usize, usize)>,
+    tree_len: usize,
+) -> Option<RangeInclusive<usize>> {
+    let (start, end) = (*level)?;
+    if start >= tree_len {
+        *level = None;
+        return None;
+    }
+
+    // Descendants of a contiguous run are themselves contiguous one level down,
+    // so it suffices to track the first and last child of each end.
+    *level = match start.checked_mul(arity) {
+        Some(child) if child < usize::MAX => {
+            let end = end.saturating_mul(arity).saturating_add(arity);
+            Some((child + 1, end))
+        }
+        _ => None,
+    };
+
+    let end = end.min(tree_len - 1);
+    Some(start..=end)
+}
@@ -1,9 +1,281 @@
+//! Traversals walk the flattened indices of a complete tree without a stack.
+//!
+//! Each of them, and each node traversal built on them, has a `max_depth` that
+//! prunes the nodes more than `depth` levels below the node the traversal starts
+//! from, which for a level-order traversal is always the root. Nodes already
+//! yielded from either end stay consumed.
+
 pub use self::in_order::InOrder;
 pub use self::level_order::LevelOrder;
 pub use self::post_order::PostOrder;
 pub use self::pre_order::PreOrder;
 
+pub(crate) use self::in_order::In;
+pub(crate) use self::post_order::Post;
+pub(crate) use self::pre_order::Pre;
+
+use crate::index::{level_start, split_flattened};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
 mod in_order;
 mod level_order;
 mod post_order;
 mod pre_order;
+
+pub trait Traversal:
+    DoubleEndedIterator + ExactSizeIterator + FusedIterator + sealed::Traversal
+{
+}
+
+pub trait MaxDepth: sealed::MaxDepth {}
+
+pub(crate) mod sealed {
+    pub trait Traversal: Iterator {
+        fn next_flattened(&mut self) -> Option<usize>;
+
+        fn next_back_flattened(&mut self) -> Option<usize>;
+
+        fn index(&self, index: usize) -> Self::Item;
+    }
+
+    pub trait MaxDepth {
+        fn max_depth(self, depth: usize) -> Self;
+    }
+}
+
+pub(crate) trait Order {
+    fn first(arity: usize, root: usize, tree_len: usize) -> usize;
+
+    fn last(arity: usize, root: usize, tree_len: usize) -> usize;
+
+    fn successor(arity: usize, index: usize, tree_len: usize) -> Option<usize>;
+
+    fn predecessor(arity: usize, index: usize, tree_len: usize) -> Option<usize>;
+
+    fn rank(arity: usize, root: usize, index: usize, tree_len: usize) -> usize;
+
+    /// Whether `index`, a descendant of `ancestor`, comes after it.
+    fn follows(arity: usize, index: usize, ancestor: usize) -> bool;
+}
+
+#[derive(Debug)]
+pub(crate) struct Walk<O> {
+    root: usize,
+    front: usize,
+    back: usize,
+    len: usize,
+    tree_len: usize,
+    order: PhantomData<O>,
+}
+
+impl<O: Order> Walk<O> {
+    pub(crate) fn new(arity: usize, root: usize, tree_len: usize) -> Self {
+        let len = forest_len(arity, root, root, tree_len);
+        let (front, back) = if len == 0 {
+            (root, root)
+        } else {
+            let front = O::first(arity, root, tree_len);
+            let back = O::last(arity, root, tree_len);
+            (front, back)
+        };
+        Self {
+            root,
+            front,
+            back,
+            len,
+            tree_len,
+            order: PhantomData,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn max_depth(mut self, arity: usize, depth: usize) -> Self {
+        let (root_depth, _) = split_flattened(arity, self.root);
+        let tree_len = depth_limit(arity, root_depth.saturating_add(depth), self.tree_len);
+        if tree_len == self.tree_len {
+            return self;
+        }
+        self.tree_len = tree_len;
+        if self.len == 0 {
+            return self;
+        }
+
+        // A pruned node sits next to its ancestor on the last kept level. If it
+        // comes after that ancestor, the ancestor has already been yielded from
+        // the front and is the next node to yield from the back; otherwise the
+        // other way round.
+        let front = pruned_ancestor(arity, self.front, tree_len);
+        let back = pruned_ancestor(arity, self.back, tree_len);
+        let front_after = front != self.front && O::follows(arity, self.front, front);
+        let back_before = back != self.back && !O::follows(arity, self.back, back);
+        let start = O::rank(arity, self.root, front, tree_len) + usize::from(front_after);
+        let end = O::rank(arity, self.root, back, tree_len) + usize::from(!back_before);
+        self.len = end.saturating_sub(start);
+        if self.len == 0 {
+            return self;
+        }
+
+        self.front = if front_after {
+            let Some(next) = O::successor(arity, front, tree_len) else {
+                unreachable!()
+            };
+            next
+        } else {
+            front
+        };
+        self.back = if back_before {
+            let Some(prev) = O::predecessor(arity, back, tree_len) else {
+                unreachable!()
+            };
+            prev
+        } else {
+            back
+        };
+        self
+    }
+
+    pub(crate) fn next(&mut self, arity: usize) -> Option<usize> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+
+        let index = self.front;
+        if self.len != 0 {
+            let Some(next) = O::successor(arity, index, self.tree_len) else {
+                unreachable!()
+            };
+            self.front = next;
+        }
+        Some(index)
+    }
+
+    pub(crate) fn next_back(&mut self, arity: usize) -> Option<usize> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+
+        let index = self.back;
+        if self.len != 0 {
+            let Some(prev) = O::predecessor(arity, index, self.tree_len) else {
+                unreachable!()
+            };
+            self.back = prev;
+        }
+        Some(index)
+    }
+}
+
+impl<O> Clone for Walk<O> {
+    fn clone(&self) -> Self {
+        Self {
+            order: PhantomData,
+            ..*self
+        }
+    }
+}
+
+pub(crate) fn depth_limit(arity: usize, depth: usize, tree_len: usize) -> usize {
+    match depth
+        .checked_add(1)
+        .and_then(|depth| level_start(arity, depth))
+    {
+        Some(next_level) => tree_len.min(next_level),
+        None => tree_len,
+    }
+}
+
+fn forest_len(arity: usize, mut first: usize, mut last: usize, tree_len: usize) -> usize {
+    let mut len = 0;
+    while first < tree_len {
+        len += last.min(tree_len - 1) - first + 1;
+        let Some(child) = first
+            .checked_mul(arity)
+            .and_then(|child| child.checked_add(1))
+        else {
+            break;
+        };
+        first = child;
+        last = last
+            .checked_mul(arity)
+            .and_then(|child| child.checked_add(arity))
+            .unwrap_or(usize::MAX);
+    }
+    len
+}
+
+fn prev_siblings_len(arity: usize, index: usize, tree_len: usize) -> usize {
+    let first = parent(arity, index) * arity + 1;
+    if index == first {
+        0
+    } else {
+        forest_len(arity, first, index - 1, tree_len)
+    }
+}
+
+const fn pruned_ancestor(arity: usize, mut index: usize, tree_len: usize) -> usize {
+    while index >= tree_len {
+        index = parent(arity, index);
+    }
+    index
+}
+
+const fn parent(arity: usize, index: usize) -> usize {
+    (index - 1) / arity
+}
+
+const fn first_child(arity: usize, index: usize, tree_len: usize) -> Option<usize> {
+    match index.checked_mul(arity) {
+        Some(child) if child < tree_len - 1 => Some(child + 1),
+        _ => None,
+    }
+}
+
+const fn last_child(arity: usize, index: usize, tree_len: usize) -> Option<usize> {
+    match first_child(arity, index, tree_len) {
+        Some(child) => {
+            let child = child.saturating_add(arity - 1);
+            if child < tree_len {
+                Some(child)
+            } else {
+                Some(tree_len - 1)
+            }
+        }
+        None => None,
+    }
+}
+
+const fn next_sibling(arity: usize, index: usize, tree_len: usize) -> Option<usize> {
+    if index == 0 || (index - 1) % arity == arity - 1 || index + 1 >= tree_len {
+        None
+    } else {
+        Some(index + 1)
+    }
+}
+
+const fn prev_sibling(arity: usize, index: usize) -> Option<usize> {
+    if index == 0 || (index - 1).is_multiple_of(arity) {
+        None
+    } else {
+        Some(index - 1)
+    }
+}
+
+const fn leftmost_descendant(arity: usize, mut index: usize, tree_len: usize) -> usize {
+    while let Some(child) = first_child(arity, index, tree_len) {
+        index = child;
+    }
+    index
+}
+
+const fn rightmost_descendant(arity: usize, mut index: usize, tree_len: usize) -> usize {
+    while let Some(child) = last_child(arity, index, tree_len) {
+        index = child;
+    }
+    index
+}
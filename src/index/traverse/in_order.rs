@@ -1,39 +1,32 @@
+use super::{
+    MaxDepth, Order, Traversal, Walk, first_child, forest_len, leftmost_descendant, next_sibling,
+    parent, sealed,
+};
 use crate::Index;
-use alloc::vec::Vec;
 use core::iter::FusedIterator;
 
 #[derive(Debug, Clone)]
-pub struct InOrder {
-    state: State,
-    stack: Vec<Index<2>>,
-    tree_len: usize,
-}
+pub struct InOrder(Walk<In>);
 
 impl InOrder {
     pub fn new(tree_len: usize) -> Self {
-        if tree_len == 0 {
-            let state = State::Done;
-            let stack = Vec::new();
-            return Self {
-                state,
-                stack,
-                tree_len,
-            };
-        }
+        Self::new_from(Index::root(), tree_len)
+    }
+
+    pub fn new_from(root: Index<2>, tree_len: usize) -> Self {
+        Self(Walk::new(2, root.to_flattened(), tree_len))
+    }
 
-        let root = Index::root();
-        let state = State::Push(root);
+    pub fn max_depth(self, depth: usize) -> Self {
+        Self(self.0.max_depth(2, depth))
+    }
 
-        let last = tree_len - 1;
-        let tree_height = Index::<2>::from_flattened(last).depth();
-        let capacity = tree_height;
-        let stack = Vec::with_capacity(capacity);
+    pub(crate) fn next_flattened(&mut self) -> Option<usize> {
+        self.0.next(2)
+    }
 
-        Self {
-            state,
-            stack,
-            tree_len,
-        }
+    pub(crate) fn next_back_flattened(&mut self) -> Option<usize> {
+        self.0.next_back(2)
     }
 }
 
@@ -41,50 +34,141 @@ impl Iterator for InOrder {
     type Item = Index<2>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            match self.state {
-                State::Push(index) => {
-                    if let Some(left_child) = index.left_child()
-                        && left_child.to_flattened() < self.tree_len
-                    {
-                        self.state = State::Push(left_child);
-                        self.stack.push(index);
-                        continue;
-                    }
-                    self.state = State::Pop;
-                    return Some(index);
-                }
-
-                State::Pop => {
-                    if let Some(index) = self.stack.pop() {
-                        if let Some(right_child) = index.right_child()
-                            && right_child.to_flattened() < self.tree_len
-                        {
-                            self.state = State::Push(right_child);
-                        }
-                        return Some(index);
-                    }
-                    self.state = State::Done;
-                    return None;
-                }
-
-                State::Done => {
-                    return None;
-                }
-            }
-        }
+        let index = self.next_flattened()?;
+        let index = Index::from_flattened(index);
+        Some(index)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.stack.len(), Some(self.tree_len))
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl ExactSizeIterator for InOrder {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl DoubleEndedIterator for InOrder {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.next_back_flattened()?;
+        let index = Index::from_flattened(index);
+        Some(index)
     }
 }
 
 impl FusedIterator for InOrder {}
 
+impl Traversal for InOrder {}
+
+impl sealed::Traversal for InOrder {
+    fn next_flattened(&mut self) -> Option<usize> {
+        self.next_flattened()
+    }
+
+    fn next_back_flattened(&mut self) -> Option<usize> {
+        self.next_back_flattened()
+    }
+
+    fn index(&self, index: usize) -> Self::Item {
+        Index::from_flattened(index)
+    }
+}
+
+impl MaxDepth for InOrder {}
+
+impl sealed::MaxDepth for InOrder {
+    fn max_depth(self, depth: usize) -> Self {
+        self.max_depth(depth)
+    }
+}
+
+/// Only defined for binary trees; every `arity` passed in is `2`.
 #[derive(Debug, Clone)]
-enum State {
-    Push(Index<2>),
-    Pop,
-    Done,
+pub(crate) struct In;
+
+impl Order for In {
+    fn first(_arity: usize, root: usize, tree_len: usize) -> usize {
+        leftmost_descendant(2, root, tree_len)
+    }
+
+    fn last(_arity: usize, root: usize, tree_len: usize) -> usize {
+        rightmost_right_descendant(root, tree_len)
+    }
+
+    fn successor(_arity: usize, index: usize, tree_len: usize) -> Option<usize> {
+        if let Some(right_child) = right_child(index, tree_len) {
+            return Some(leftmost_descendant(2, right_child, tree_len));
+        }
+
+        let mut ancestor = index;
+        loop {
+            if ancestor == 0 {
+                return None;
+            }
+            let is_left_child = ancestor % 2 == 1;
+            ancestor = parent(2, ancestor);
+            if is_left_child {
+                return Some(ancestor);
+            }
+        }
+    }
+
+    fn predecessor(_arity: usize, index: usize, tree_len: usize) -> Option<usize> {
+        if let Some(left_child) = first_child(2, index, tree_len) {
+            return Some(rightmost_right_descendant(left_child, tree_len));
+        }
+
+        let mut ancestor = index;
+        loop {
+            if ancestor == 0 {
+                return None;
+            }
+            let is_right_child = ancestor.is_multiple_of(2);
+            ancestor = parent(2, ancestor);
+            if is_right_child {
+                return Some(ancestor);
+            }
+        }
+    }
+
+    fn rank(_arity: usize, root: usize, mut index: usize, tree_len: usize) -> usize {
+        let mut rank = match first_child(2, index, tree_len) {
+            Some(left_child) => forest_len(2, left_child, left_child, tree_len),
+            None => 0,
+        };
+        while index != root {
+            if index.is_multiple_of(2) {
+                rank += forest_len(2, index - 1, index - 1, tree_len) + 1;
+            }
+            index = parent(2, index);
+        }
+        rank
+    }
+
+    fn follows(_arity: usize, index: usize, ancestor: usize) -> bool {
+        !is_left_descendant(index, ancestor)
+    }
+}
+
+const fn right_child(index: usize, tree_len: usize) -> Option<usize> {
+    match first_child(2, index, tree_len) {
+        Some(left_child) => next_sibling(2, left_child, tree_len),
+        None => None,
+    }
+}
+
+const fn is_left_descendant(mut index: usize, ancestor: usize) -> bool {
+    while parent(2, index) != ancestor {
+        index = parent(2, index);
+    }
+    index % 2 == 1
+}
+
+const fn rightmost_right_descendant(mut index: usize, tree_len: usize) -> usize {
+    while let Some(child) = right_child(index, tree_len) {
+        index = child;
+    }
+    index
 }
@@ -1,3 +1,4 @@
+use super::{MaxDepth, Traversal, depth_limit, sealed};
 use crate::{Index, IndexRange};
 use core::iter::FusedIterator;
 
@@ -17,6 +18,14 @@ impl<const N: usize> LevelOrder<N> {
 
         Self(range)
     }
+
+    pub fn max_depth(self, depth: usize) -> Self {
+        let limit = depth_limit(N, depth, usize::MAX);
+        if limit == usize::MAX || self.0.is_empty() {
+            return self;
+        }
+        Self(self.0.cap(limit))
+    }
 }
 
 impl<const N: usize> Iterator for LevelOrder<N> {
@@ -45,3 +54,27 @@ impl<const N: usize> DoubleEndedIterator for LevelOrder<N> {
 }
 
 impl<const N: usize> FusedIterator for LevelOrder<N> {}
+
+impl<const N: usize> Traversal for LevelOrder<N> {}
+
+impl<const N: usize> sealed::Traversal for LevelOrder<N> {
+    fn next_flattened(&mut self) -> Option<usize> {
+        self.0.0.next()
+    }
+
+    fn next_back_flattened(&mut self) -> Option<usize> {
+        self.0.0.next_back()
+    }
+
+    fn index(&self, index: usize) -> Self::Item {
+        Index::from_flattened(index)
+    }
+}
+
+impl<const N: usize> MaxDepth for LevelOrder<N> {}
+
+impl<const N: usize> sealed::MaxDepth for LevelOrder<N> {
+    fn max_depth(self, depth: usize) -> Self {
+        self.max_depth(depth)
+    }
+}
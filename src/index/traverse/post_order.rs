@@ -1,34 +1,32 @@
-use crate::{Index, IndexRange};
-use alloc::vec::Vec;
+use super::{
+    MaxDepth, Order, Traversal, Walk, forest_len, last_child, leftmost_descendant, next_sibling,
+    parent, prev_sibling, prev_siblings_len, sealed,
+};
+use crate::Index;
 use core::iter::FusedIterator;
 
 #[derive(Debug, Clone)]
-pub struct PostOrder<const N: usize> {
-    stack: Vec<Frame<N>>,
-    tree_len: usize,
-}
+pub struct PostOrder<const N: usize>(Walk<Post>);
 
 impl<const N: usize> PostOrder<N> {
     pub fn new(tree_len: usize) -> Self {
-        if tree_len == 0 {
-            let stack = Vec::new();
-            return Self { stack, tree_len };
-        }
+        Self::new_from(Index::root(), tree_len)
+    }
 
-        let last = tree_len - 1;
-        let tree_height = Index::<N>::from_flattened(last).depth();
-        let capacity = tree_height + 1;
-        let mut stack = Vec::with_capacity(capacity);
+    pub fn new_from(root: Index<N>, tree_len: usize) -> Self {
+        Self(Walk::new(N, root.to_flattened(), tree_len))
+    }
+
+    pub fn max_depth(self, depth: usize) -> Self {
+        Self(self.0.max_depth(N, depth))
+    }
 
-        let root = Index::root();
-        let children = root.iter_children().cap(tree_len);
-        let frame = Frame {
-            index: root,
-            children,
-        };
-        stack.push(frame);
+    pub(crate) fn next_flattened(&mut self) -> Option<usize> {
+        self.0.next(N)
+    }
 
-        Self { stack, tree_len }
+    pub(crate) fn next_back_flattened(&mut self) -> Option<usize> {
+        self.0.next_back(N)
     }
 }
 
@@ -36,33 +34,105 @@ impl<const N: usize> Iterator for PostOrder<N> {
     type Item = Index<N>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let frame = self.stack.last_mut()?;
-            if let Some(child) = frame.children.next() {
-                let grandchildren = child.iter_children().cap(self.tree_len);
-                let frame = Frame {
-                    index: child,
-                    children: grandchildren,
-                };
-                self.stack.push(frame);
-                continue;
-            }
-            let Some(frame) = self.stack.pop() else {
-                unreachable!()
-            };
-            return Some(frame.index);
-        }
+        let index = self.next_flattened()?;
+        let index = Index::from_flattened(index);
+        Some(index)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.stack.len(), Some(self.tree_len))
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for PostOrder<N> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for PostOrder<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.next_back_flattened()?;
+        let index = Index::from_flattened(index);
+        Some(index)
     }
 }
 
 impl<const N: usize> FusedIterator for PostOrder<N> {}
 
+impl<const N: usize> Traversal for PostOrder<N> {}
+
+impl<const N: usize> sealed::Traversal for PostOrder<N> {
+    fn next_flattened(&mut self) -> Option<usize> {
+        self.next_flattened()
+    }
+
+    fn next_back_flattened(&mut self) -> Option<usize> {
+        self.next_back_flattened()
+    }
+
+    fn index(&self, index: usize) -> Self::Item {
+        Index::from_flattened(index)
+    }
+}
+
+impl<const N: usize> MaxDepth for PostOrder<N> {}
+
+impl<const N: usize> sealed::MaxDepth for PostOrder<N> {
+    fn max_depth(self, depth: usize) -> Self {
+        self.max_depth(depth)
+    }
+}
+
 #[derive(Debug, Clone)]
-struct Frame<const N: usize> {
-    index: Index<N>,
-    children: IndexRange<N>,
+pub(crate) struct Post;
+
+impl Order for Post {
+    fn first(arity: usize, root: usize, tree_len: usize) -> usize {
+        leftmost_descendant(arity, root, tree_len)
+    }
+
+    fn last(_arity: usize, root: usize, _tree_len: usize) -> usize {
+        root
+    }
+
+    fn successor(arity: usize, index: usize, tree_len: usize) -> Option<usize> {
+        if index == 0 {
+            None
+        } else if let Some(sibling) = next_sibling(arity, index, tree_len) {
+            Some(leftmost_descendant(arity, sibling, tree_len))
+        } else {
+            Some(parent(arity, index))
+        }
+    }
+
+    fn predecessor(arity: usize, index: usize, tree_len: usize) -> Option<usize> {
+        if let Some(child) = last_child(arity, index, tree_len) {
+            return Some(child);
+        }
+
+        let mut ancestor = index;
+        loop {
+            if ancestor == 0 {
+                return None;
+            }
+            if let Some(sibling) = prev_sibling(arity, ancestor) {
+                return Some(sibling);
+            }
+            ancestor = parent(arity, ancestor);
+        }
+    }
+
+    fn rank(arity: usize, root: usize, mut index: usize, tree_len: usize) -> usize {
+        let mut rank = forest_len(arity, index, index, tree_len) - 1;
+        while index != root {
+            rank += prev_siblings_len(arity, index, tree_len);
+            index = parent(arity, index);
+        }
+        rank
+    }
+
+    fn follows(_arity: usize, _index: usize, _ancestor: usize) -> bool {
+        false
+    }
 }
@@ -1,29 +1,32 @@
+use super::{
+    MaxDepth, Order, Traversal, Walk, first_child, next_sibling, parent, prev_sibling,
+    prev_siblings_len, rightmost_descendant, sealed,
+};
 use crate::Index;
-use alloc::vec::Vec;
 use core::iter::FusedIterator;
 
 #[derive(Debug, Clone)]
-pub struct PreOrder<const N: usize> {
-    stack: Vec<Index<N>>,
-    tree_len: usize,
-}
+pub struct PreOrder<const N: usize>(Walk<Pre>);
 
 impl<const N: usize> PreOrder<N> {
     pub fn new(tree_len: usize) -> Self {
-        if tree_len == 0 {
-            let stack = Vec::new();
-            return Self { stack, tree_len };
-        }
+        Self::new_from(Index::root(), tree_len)
+    }
 
-        let last = tree_len - 1;
-        let tree_height = Index::<N>::from_flattened(last).depth();
-        let capacity = tree_height.saturating_mul(N - 1).saturating_add(1);
-        let mut stack = Vec::with_capacity(capacity);
+    pub fn new_from(root: Index<N>, tree_len: usize) -> Self {
+        Self(Walk::new(N, root.to_flattened(), tree_len))
+    }
+
+    pub fn max_depth(self, depth: usize) -> Self {
+        Self(self.0.max_depth(N, depth))
+    }
 
-        let root = Index::root();
-        stack.push(root);
+    pub(crate) fn next_flattened(&mut self) -> Option<usize> {
+        self.0.next(N)
+    }
 
-        Self { stack, tree_len }
+    pub(crate) fn next_back_flattened(&mut self) -> Option<usize> {
+        self.0.next_back(N)
     }
 }
 
@@ -31,17 +34,105 @@ impl<const N: usize> Iterator for PreOrder<N> {
     type Item = Index<N>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let index = self.stack.pop()?;
-        let children = index.iter_children().cap(self.tree_len);
-        for child in children.rev() {
-            self.stack.push(child);
-        }
+        let index = self.next_flattened()?;
+        let index = Index::from_flattened(index);
         Some(index)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.stack.len(), Some(self.tree_len))
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for PreOrder<N> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for PreOrder<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.next_back_flattened()?;
+        let index = Index::from_flattened(index);
+        Some(index)
     }
 }
 
 impl<const N: usize> FusedIterator for PreOrder<N> {}
+
+impl<const N: usize> Traversal for PreOrder<N> {}
+
+impl<const N: usize> sealed::Traversal for PreOrder<N> {
+    fn next_flattened(&mut self) -> Option<usize> {
+        self.next_flattened()
+    }
+
+    fn next_back_flattened(&mut self) -> Option<usize> {
+        self.next_back_flattened()
+    }
+
+    fn index(&self, index: usize) -> Self::Item {
+        Index::from_flattened(index)
+    }
+}
+
+impl<const N: usize> MaxDepth for PreOrder<N> {}
+
+impl<const N: usize> sealed::MaxDepth for PreOrder<N> {
+    fn max_depth(self, depth: usize) -> Self {
+        self.max_depth(depth)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Pre;
+
+impl Order for Pre {
+    fn first(_arity: usize, root: usize, _tree_len: usize) -> usize {
+        root
+    }
+
+    fn last(arity: usize, root: usize, tree_len: usize) -> usize {
+        rightmost_descendant(arity, root, tree_len)
+    }
+
+    fn successor(arity: usize, index: usize, tree_len: usize) -> Option<usize> {
+        if let Some(child) = first_child(arity, index, tree_len) {
+            return Some(child);
+        }
+
+        let mut ancestor = index;
+        loop {
+            if ancestor == 0 {
+                return None;
+            }
+            if let Some(sibling) = next_sibling(arity, ancestor, tree_len) {
+                return Some(sibling);
+            }
+            ancestor = parent(arity, ancestor);
+        }
+    }
+
+    fn predecessor(arity: usize, index: usize, tree_len: usize) -> Option<usize> {
+        if index == 0 {
+            None
+        } else if let Some(sibling) = prev_sibling(arity, index) {
+            Some(rightmost_descendant(arity, sibling, tree_len))
+        } else {
+            Some(parent(arity, index))
+        }
+    }
+
+    fn rank(arity: usize, root: usize, mut index: usize, tree_len: usize) -> usize {
+        let mut rank = 0;
+        while index != root {
+            rank += prev_siblings_len(arity, index, tree_len) + 1;
+            index = parent(arity, index);
+        }
+        rank
+    }
+
+    fn follows(_arity: usize, _index: usize, _ancestor: usize) -> bool {
+        true
+    }
+}
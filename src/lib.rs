@@ -1,12 +1,44 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub use self::array_tree::ArrayTree;
+pub use self::display::Display;
+pub use self::dyn_index::{DynIndex, DynIndexRange};
+pub use self::dyn_slice_tree::{DynSliceTree, DynSliceTreeMut};
+pub use self::error::Error;
+pub use self::export::GraphOptions;
 pub use self::index::{Index, IndexRange};
 pub use self::slice_tree::SliceTree;
-
+#[cfg(feature = "alloc")]
+pub use self::vec_tree::VecTree;
+
+use self::display::NodeFormatter;
+use core::fmt;
+use core::ops::RangeBounds;
+
+pub mod array_tree;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+pub mod display;
+pub mod dyn_index;
+pub mod dyn_slice_tree;
+pub mod error;
+pub mod export;
+pub mod heap;
 pub mod index;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
+#[cfg(feature = "alloc")]
+pub mod s_tree;
+#[cfg(feature = "alloc")]
+pub mod segment_tree;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod slice_tree;
+#[cfg(feature = "alloc")]
+pub mod vec_tree;
 
 pub trait CompleteTree<const N: usize> {
     type Node;
@@ -51,6 +83,70 @@ pub trait CompleteTree<const N: usize> {
     where
         Self: 'a;
 
+    type LevelOrderIndexed<'a>: Iterator<Item = (Index<N>, &'a Self::Node)>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexedMut<'a>: Iterator<Item = (Index<N>, &'a mut Self::Node)>
+    where
+        Self: 'a;
+
+    type PreOrderIndexed<'a>: Iterator<Item = (Index<N>, &'a Self::Node)>
+    where
+        Self: 'a;
+
+    type PreOrderIndexedMut<'a>: Iterator<Item = (Index<N>, &'a mut Self::Node)>
+    where
+        Self: 'a;
+
+    type PostOrderIndexed<'a>: Iterator<Item = (Index<N>, &'a Self::Node)>
+    where
+        Self: 'a;
+
+    type PostOrderIndexedMut<'a>: Iterator<Item = (Index<N>, &'a mut Self::Node)>
+    where
+        Self: 'a;
+
+    type Ancestors<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type IterSiblings<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type IterSiblingsMut<'a>: Iterator<Item = &'a mut Self::Node>
+    where
+        Self: 'a;
+
+    type Subtree<'a>
+    where
+        Self: 'a;
+
+    type IterLeaves<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type IterLeavesMut<'a>: Iterator<Item = &'a mut Self::Node>
+    where
+        Self: 'a;
+
+    type IterInternal<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type IterInternalMut<'a>: Iterator<Item = &'a mut Self::Node>
+    where
+        Self: 'a;
+
+    type IterLevels<'a>: Iterator<Item = Self::IterLevel<'a>>
+    where
+        Self: 'a;
+
+    type IterLevelsMut<'a>: Iterator<Item = Self::IterLevelMut<'a>>
+    where
+        Self: 'a;
+
     fn len(&self) -> usize;
 
     fn is_empty(&self) -> bool {
@@ -66,6 +162,65 @@ pub trait CompleteTree<const N: usize> {
         index.depth()
     }
 
+    fn internal_count(&self) -> usize {
+        if self.len() < 2 {
+            return 0;
+        }
+        (self.len() - 2) / N + 1
+    }
+
+    fn leaf_count(&self) -> usize {
+        self.len() - self.internal_count()
+    }
+
+    fn first_leaf_index(&self) -> Option<Index<N>> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = self.internal_count();
+        Some(Index::from_flattened(index))
+    }
+
+    fn last_internal_index(&self) -> Option<Index<N>> {
+        let index = self.internal_count().checked_sub(1)?;
+        Some(Index::from_flattened(index))
+    }
+
+    fn is_leaf(&self, index: Index<N>) -> bool {
+        let index = index.to_flattened();
+        index < self.len() && index >= self.internal_count()
+    }
+
+    fn display(&self) -> Display<'_, N, Self, NodeFormatter<Self::Node>>
+    where
+        Self::Node: fmt::Display,
+    {
+        Display::new(self, <Self::Node as fmt::Display>::fmt)
+    }
+
+    fn display_with<F>(&self, formatter: F) -> Display<'_, N, Self, F>
+    where
+        F: Fn(&Self::Node, &mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        Display::new(self, formatter)
+    }
+
+    fn write_dot<W, F>(&self, out: &mut W, options: &GraphOptions<'_, N, F>) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+        F: Fn(&Self::Node, &mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        export::write_dot(self, out, options)
+    }
+
+    fn write_mermaid<W, F>(&self, out: &mut W, options: &GraphOptions<'_, N, F>) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+        F: Fn(&Self::Node, &mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        export::write_mermaid(self, out, options)
+    }
+
     fn swap(&mut self, index_a: Index<N>, index_b: Index<N>) -> Option<()>;
 
     fn replace(&mut self, index: Index<N>, node: Self::Node) -> Option<Self::Node>;
@@ -74,6 +229,46 @@ pub trait CompleteTree<const N: usize> {
 
     fn node_mut(&mut self, index: Index<N>) -> Option<&mut Self::Node>;
 
+    fn try_swap(&mut self, index_a: Index<N>, index_b: Index<N>) -> Result<(), Error> {
+        let len = self.len();
+        let flattened_a = index_a.to_flattened();
+        let flattened_b = index_b.to_flattened();
+        let index = if flattened_a >= len {
+            flattened_a
+        } else {
+            flattened_b
+        };
+        self.swap(index_a, index_b)
+            .ok_or(Error::OutOfBounds { index, len })
+    }
+
+    fn try_replace(&mut self, index: Index<N>, node: Self::Node) -> Result<Self::Node, Error> {
+        let len = self.len();
+        let flattened = index.to_flattened();
+        self.replace(index, node).ok_or(Error::OutOfBounds {
+            index: flattened,
+            len,
+        })
+    }
+
+    fn try_node(&self, index: Index<N>) -> Result<&Self::Node, Error> {
+        let len = self.len();
+        let flattened = index.to_flattened();
+        self.node(index).ok_or(Error::OutOfBounds {
+            index: flattened,
+            len,
+        })
+    }
+
+    fn try_node_mut(&mut self, index: Index<N>) -> Result<&mut Self::Node, Error> {
+        let len = self.len();
+        let flattened = index.to_flattened();
+        self.node_mut(index).ok_or(Error::OutOfBounds {
+            index: flattened,
+            len,
+        })
+    }
+
     fn root(&self) -> Option<&Self::Node> {
         let index = Index::root();
         self.node(index)
@@ -112,6 +307,8 @@ pub trait CompleteTree<const N: usize> {
         self.node_mut(index)
     }
 
+    fn ancestors(&self, index: Index<N>) -> Option<Self::Ancestors<'_>>;
+
     fn first_child(&self, index: Index<N>) -> Option<&Self::Node> {
         let index = index.first_child()?;
         self.node(index)
@@ -142,14 +339,76 @@ pub trait CompleteTree<const N: usize> {
         self.node_mut(index)
     }
 
+    fn next_sibling(&self, index: Index<N>) -> Option<&Self::Node> {
+        let index = index.next_sibling()?;
+        self.node(index)
+    }
+
+    fn next_sibling_mut(&mut self, index: Index<N>) -> Option<&mut Self::Node> {
+        let index = index.next_sibling()?;
+        self.node_mut(index)
+    }
+
+    fn prev_sibling(&self, index: Index<N>) -> Option<&Self::Node> {
+        let index = index.prev_sibling()?;
+        self.node(index)
+    }
+
+    fn prev_sibling_mut(&mut self, index: Index<N>) -> Option<&mut Self::Node> {
+        let index = index.prev_sibling()?;
+        self.node_mut(index)
+    }
+
+    fn next_in_level(&self, index: Index<N>) -> Option<&Self::Node> {
+        let index = index.next_in_level()?;
+        self.node(index)
+    }
+
+    fn next_in_level_mut(&mut self, index: Index<N>) -> Option<&mut Self::Node> {
+        let index = index.next_in_level()?;
+        self.node_mut(index)
+    }
+
+    fn prev_in_level(&self, index: Index<N>) -> Option<&Self::Node> {
+        let index = index.prev_in_level()?;
+        self.node(index)
+    }
+
+    fn prev_in_level_mut(&mut self, index: Index<N>) -> Option<&mut Self::Node> {
+        let index = index.prev_in_level()?;
+        self.node_mut(index)
+    }
+
+    fn iter_siblings(&self, index: Index<N>) -> Option<Self::IterSiblings<'_>>;
+
+    fn iter_siblings_mut(&mut self, index: Index<N>) -> Option<Self::IterSiblingsMut<'_>>;
+
     fn iter_children(&self, index: Index<N>) -> Option<Self::IterChildren<'_>>;
 
     fn iter_children_mut(&mut self, index: Index<N>) -> Option<Self::IterChildrenMut<'_>>;
 
+    fn subtree(&self, index: Index<N>) -> Option<Self::Subtree<'_>>;
+
     fn iter_level(&self, depth: usize) -> Option<Self::IterLevel<'_>>;
 
     fn iter_level_mut(&mut self, depth: usize) -> Option<Self::IterLevelMut<'_>>;
 
+    fn iter_leaves(&self) -> Self::IterLeaves<'_>;
+
+    fn iter_leaves_mut(&mut self) -> Self::IterLeavesMut<'_>;
+
+    fn iter_internal(&self) -> Self::IterInternal<'_>;
+
+    fn iter_internal_mut(&mut self) -> Self::IterInternalMut<'_>;
+
+    fn iter_levels<R>(&self, depths: R) -> Self::IterLevels<'_>
+    where
+        R: RangeBounds<usize>;
+
+    fn iter_levels_mut<R>(&mut self, depths: R) -> Self::IterLevelsMut<'_>
+    where
+        R: RangeBounds<usize>;
+
     fn traverse_level_order(&self) -> Self::LevelOrder<'_>;
 
     fn traverse_level_order_mut(&mut self) -> Self::LevelOrderMut<'_>;
@@ -161,6 +420,26 @@ pub trait CompleteTree<const N: usize> {
     fn traverse_post_order(&self) -> Self::PostOrder<'_>;
 
     fn traverse_post_order_mut(&mut self) -> Self::PostOrderMut<'_>;
+
+    fn traverse_pre_order_from(&self, index: Index<N>) -> Option<Self::PreOrder<'_>>;
+
+    fn traverse_pre_order_from_mut(&mut self, index: Index<N>) -> Option<Self::PreOrderMut<'_>>;
+
+    fn traverse_post_order_from(&self, index: Index<N>) -> Option<Self::PostOrder<'_>>;
+
+    fn traverse_post_order_from_mut(&mut self, index: Index<N>) -> Option<Self::PostOrderMut<'_>>;
+
+    fn traverse_level_order_indexed(&self) -> Self::LevelOrderIndexed<'_>;
+
+    fn traverse_level_order_indexed_mut(&mut self) -> Self::LevelOrderIndexedMut<'_>;
+
+    fn traverse_pre_order_indexed(&self) -> Self::PreOrderIndexed<'_>;
+
+    fn traverse_pre_order_indexed_mut(&mut self) -> Self::PreOrderIndexedMut<'_>;
+
+    fn traverse_post_order_indexed(&self) -> Self::PostOrderIndexed<'_>;
+
+    fn traverse_post_order_indexed_mut(&mut self) -> Self::PostOrderIndexedMut<'_>;
 }
 
 pub trait CompleteBinaryTree: CompleteTree<2> {
@@ -172,6 +451,14 @@ pub trait CompleteBinaryTree: CompleteTree<2> {
     where
         Self: 'a;
 
+    type InOrderIndexed<'a>: Iterator<Item = (Index<2>, &'a Self::Node)>
+    where
+        Self: 'a;
+
+    type InOrderIndexedMut<'a>: Iterator<Item = (Index<2>, &'a mut Self::Node)>
+    where
+        Self: 'a;
+
     fn left_child(&self, index: Index<2>) -> Option<&Self::Node> {
         self.first_child(index)
     }
@@ -191,4 +478,365 @@ pub trait CompleteBinaryTree: CompleteTree<2> {
     fn traverse_in_order(&self) -> Self::InOrder<'_>;
 
     fn traverse_in_order_mut(&mut self) -> Self::InOrderMut<'_>;
+
+    fn traverse_in_order_from(&self, index: Index<2>) -> Option<Self::InOrder<'_>>;
+
+    fn traverse_in_order_from_mut(&mut self, index: Index<2>) -> Option<Self::InOrderMut<'_>>;
+
+    fn traverse_in_order_indexed(&self) -> Self::InOrderIndexed<'_>;
+
+    fn traverse_in_order_indexed_mut(&mut self) -> Self::InOrderIndexedMut<'_>;
+}
+
+pub trait DynCompleteTree {
+    type Node;
+
+    type IterChildren<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type IterLevel<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type LevelOrder<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type PreOrder<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type PostOrder<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexed<'a>: Iterator<Item = (DynIndex, &'a Self::Node)>
+    where
+        Self: 'a;
+
+    type PreOrderIndexed<'a>: Iterator<Item = (DynIndex, &'a Self::Node)>
+    where
+        Self: 'a;
+
+    type PostOrderIndexed<'a>: Iterator<Item = (DynIndex, &'a Self::Node)>
+    where
+        Self: 'a;
+
+    type InOrder<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type InOrderIndexed<'a>: Iterator<Item = (DynIndex, &'a Self::Node)>
+    where
+        Self: 'a;
+
+    type Ancestors<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type IterSiblings<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type Subtree<'a>
+    where
+        Self: 'a;
+
+    type IterLeaves<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    type IterLevels<'a>: Iterator<Item = Self::IterLevel<'a>>
+    where
+        Self: 'a;
+
+    fn arity(&self) -> usize;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn height(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        let index = self.len() - 1;
+        let Some(index) = DynIndex::from_flattened(self.arity(), index) else {
+            unreachable!()
+        };
+        index.depth()
+    }
+
+    fn internal_count(&self) -> usize {
+        if self.len() < 2 {
+            return 0;
+        }
+        (self.len() - 2) / self.arity() + 1
+    }
+
+    fn leaf_count(&self) -> usize {
+        self.len() - self.internal_count()
+    }
+
+    fn node(&self, index: DynIndex) -> Option<&Self::Node>;
+
+    fn try_node(&self, index: DynIndex) -> Result<&Self::Node, Error> {
+        self.node(index)
+            .ok_or_else(|| dyn_index_error(self.arity(), self.len(), index))
+    }
+
+    fn root(&self) -> Option<&Self::Node> {
+        let index = DynIndex::root(self.arity())?;
+        self.node(index)
+    }
+
+    fn last(&self) -> Option<&Self::Node> {
+        let index = self.len().checked_sub(1)?;
+        let index = DynIndex::from_flattened(self.arity(), index)?;
+        self.node(index)
+    }
+
+    fn parent(&self, index: DynIndex) -> Option<&Self::Node> {
+        let index = index.parent()?;
+        self.node(index)
+    }
+
+    fn ancestors(&self, index: DynIndex) -> Option<Self::Ancestors<'_>>;
+
+    fn first_child(&self, index: DynIndex) -> Option<&Self::Node> {
+        let index = index.first_child()?;
+        self.node(index)
+    }
+
+    fn last_child(&self, index: DynIndex) -> Option<&Self::Node> {
+        let index = index.last_child()?;
+        self.node(index)
+    }
+
+    fn child(&self, index: DynIndex, n: usize) -> Option<&Self::Node> {
+        let index = index.child(n)?;
+        self.node(index)
+    }
+
+    fn next_sibling(&self, index: DynIndex) -> Option<&Self::Node> {
+        let index = index.next_sibling()?;
+        self.node(index)
+    }
+
+    fn prev_sibling(&self, index: DynIndex) -> Option<&Self::Node> {
+        let index = index.prev_sibling()?;
+        self.node(index)
+    }
+
+    fn iter_siblings(&self, index: DynIndex) -> Option<Self::IterSiblings<'_>>;
+
+    fn iter_children(&self, index: DynIndex) -> Option<Self::IterChildren<'_>>;
+
+    fn subtree(&self, index: DynIndex) -> Option<Self::Subtree<'_>>;
+
+    fn iter_level(&self, depth: usize) -> Option<Self::IterLevel<'_>>;
+
+    fn iter_leaves(&self) -> Self::IterLeaves<'_>;
+
+    fn iter_levels<R>(&self, depths: R) -> Self::IterLevels<'_>
+    where
+        R: RangeBounds<usize>;
+
+    fn traverse_level_order(&self) -> Self::LevelOrder<'_>;
+
+    fn traverse_pre_order(&self) -> Self::PreOrder<'_>;
+
+    fn traverse_post_order(&self) -> Self::PostOrder<'_>;
+
+    fn traverse_pre_order_from(&self, index: DynIndex) -> Option<Self::PreOrder<'_>>;
+
+    fn traverse_post_order_from(&self, index: DynIndex) -> Option<Self::PostOrder<'_>>;
+
+    fn traverse_level_order_indexed(&self) -> Self::LevelOrderIndexed<'_>;
+
+    fn traverse_pre_order_indexed(&self) -> Self::PreOrderIndexed<'_>;
+
+    fn traverse_post_order_indexed(&self) -> Self::PostOrderIndexed<'_>;
+
+    /// Fails with [`Error::ArityMismatch`] unless the tree is binary.
+    fn traverse_in_order(&self) -> Result<Self::InOrder<'_>, Error>;
+
+    fn traverse_in_order_from(&self, index: DynIndex) -> Result<Self::InOrder<'_>, Error>;
+
+    fn traverse_in_order_indexed(&self) -> Result<Self::InOrderIndexed<'_>, Error>;
+}
+
+pub trait DynCompleteTreeMut: DynCompleteTree {
+    type IterChildrenMut<'a>: Iterator<Item = &'a mut Self::Node>
+    where
+        Self: 'a;
+
+    type IterLevelMut<'a>: Iterator<Item = &'a mut Self::Node>
+    where
+        Self: 'a;
+
+    type LevelOrderMut<'a>: Iterator<Item = &'a mut Self::Node>
+    where
+        Self: 'a;
+
+    type PreOrderMut<'a>: Iterator<Item = &'a mut Self::Node>
+    where
+        Self: 'a;
+
+    type PostOrderMut<'a>: Iterator<Item = &'a mut Self::Node>
+    where
+        Self: 'a;
+
+    type InOrderMut<'a>: Iterator<Item = &'a mut Self::Node>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexedMut<'a>: Iterator<Item = (DynIndex, &'a mut Self::Node)>
+    where
+        Self: 'a;
+
+    type PreOrderIndexedMut<'a>: Iterator<Item = (DynIndex, &'a mut Self::Node)>
+    where
+        Self: 'a;
+
+    type PostOrderIndexedMut<'a>: Iterator<Item = (DynIndex, &'a mut Self::Node)>
+    where
+        Self: 'a;
+
+    type InOrderIndexedMut<'a>: Iterator<Item = (DynIndex, &'a mut Self::Node)>
+    where
+        Self: 'a;
+
+    type IterSiblingsMut<'a>: Iterator<Item = &'a mut Self::Node>
+    where
+        Self: 'a;
+
+    type IterLeavesMut<'a>: Iterator<Item = &'a mut Self::Node>
+    where
+        Self: 'a;
+
+    type IterLevelsMut<'a>: Iterator<Item = Self::IterLevelMut<'a>>
+    where
+        Self: 'a;
+
+    fn swap(&mut self, index_a: DynIndex, index_b: DynIndex) -> Option<()>;
+
+    fn replace(&mut self, index: DynIndex, node: Self::Node) -> Option<Self::Node>;
+
+    fn node_mut(&mut self, index: DynIndex) -> Option<&mut Self::Node>;
+
+    fn try_swap(&mut self, index_a: DynIndex, index_b: DynIndex) -> Result<(), Error> {
+        let arity = self.arity();
+        let len = self.len();
+        self.try_node(index_a)?;
+        self.swap(index_a, index_b)
+            .ok_or_else(|| dyn_index_error(arity, len, index_b))
+    }
+
+    fn try_replace(&mut self, index: DynIndex, node: Self::Node) -> Result<Self::Node, Error> {
+        let arity = self.arity();
+        let len = self.len();
+        self.replace(index, node)
+            .ok_or_else(|| dyn_index_error(arity, len, index))
+    }
+
+    fn try_node_mut(&mut self, index: DynIndex) -> Result<&mut Self::Node, Error> {
+        let arity = self.arity();
+        let len = self.len();
+        self.node_mut(index)
+            .ok_or_else(|| dyn_index_error(arity, len, index))
+    }
+
+    fn root_mut(&mut self) -> Option<&mut Self::Node> {
+        let index = DynIndex::root(self.arity())?;
+        self.node_mut(index)
+    }
+
+    fn last_mut(&mut self) -> Option<&mut Self::Node> {
+        let index = self.len().checked_sub(1)?;
+        let index = DynIndex::from_flattened(self.arity(), index)?;
+        self.node_mut(index)
+    }
+
+    fn parent_mut(&mut self, index: DynIndex) -> Option<&mut Self::Node> {
+        let index = index.parent()?;
+        self.node_mut(index)
+    }
+
+    fn first_child_mut(&mut self, index: DynIndex) -> Option<&mut Self::Node> {
+        let index = index.first_child()?;
+        self.node_mut(index)
+    }
+
+    fn last_child_mut(&mut self, index: DynIndex) -> Option<&mut Self::Node> {
+        let index = index.last_child()?;
+        self.node_mut(index)
+    }
+
+    fn child_mut(&mut self, index: DynIndex, n: usize) -> Option<&mut Self::Node> {
+        let index = index.child(n)?;
+        self.node_mut(index)
+    }
+
+    fn next_sibling_mut(&mut self, index: DynIndex) -> Option<&mut Self::Node> {
+        let index = index.next_sibling()?;
+        self.node_mut(index)
+    }
+
+    fn prev_sibling_mut(&mut self, index: DynIndex) -> Option<&mut Self::Node> {
+        let index = index.prev_sibling()?;
+        self.node_mut(index)
+    }
+
+    fn iter_siblings_mut(&mut self, index: DynIndex) -> Option<Self::IterSiblingsMut<'_>>;
+
+    fn iter_children_mut(&mut self, index: DynIndex) -> Option<Self::IterChildrenMut<'_>>;
+
+    fn iter_level_mut(&mut self, depth: usize) -> Option<Self::IterLevelMut<'_>>;
+
+    fn iter_leaves_mut(&mut self) -> Self::IterLeavesMut<'_>;
+
+    fn iter_levels_mut<R>(&mut self, depths: R) -> Self::IterLevelsMut<'_>
+    where
+        R: RangeBounds<usize>;
+
+    fn traverse_level_order_mut(&mut self) -> Self::LevelOrderMut<'_>;
+
+    fn traverse_pre_order_mut(&mut self) -> Self::PreOrderMut<'_>;
+
+    fn traverse_post_order_mut(&mut self) -> Self::PostOrderMut<'_>;
+
+    fn traverse_pre_order_from_mut(&mut self, index: DynIndex) -> Option<Self::PreOrderMut<'_>>;
+
+    fn traverse_post_order_from_mut(&mut self, index: DynIndex) -> Option<Self::PostOrderMut<'_>>;
+
+    fn traverse_level_order_indexed_mut(&mut self) -> Self::LevelOrderIndexedMut<'_>;
+
+    fn traverse_pre_order_indexed_mut(&mut self) -> Self::PreOrderIndexedMut<'_>;
+
+    fn traverse_post_order_indexed_mut(&mut self) -> Self::PostOrderIndexedMut<'_>;
+
+    /// Fails with [`Error::ArityMismatch`] unless the tree is binary.
+    fn traverse_in_order_mut(&mut self) -> Result<Self::InOrderMut<'_>, Error>;
+
+    fn traverse_in_order_from_mut(
+        &mut self,
+        index: DynIndex,
+    ) -> Result<Self::InOrderMut<'_>, Error>;
+
+    fn traverse_in_order_indexed_mut(&mut self) -> Result<Self::InOrderIndexedMut<'_>, Error>;
+}
+
+/// Blames a mismatched arity before a position past the end of the tree.
+fn dyn_index_error(arity: usize, len: usize, index: DynIndex) -> Error {
+    if index.arity() != arity {
+        let expected = arity;
+        let found = index.arity();
+        return Error::ArityMismatch { expected, found };
+    }
+    let index = index.to_flattened();
+    Error::OutOfBounds { index, len }
 }
@@ -0,0 +1,91 @@
+use crate::{SliceTree, VecTree};
+use ::rkyv::bytecheck::CheckBytes;
+use ::rkyv::rancor::{Fallible, Source};
+use ::rkyv::ser::{Allocator, Writer};
+use ::rkyv::vec::{ArchivedVec, VecResolver};
+use ::rkyv::{Archive, Deserialize, DeserializeUnsized, Place, Portable, Serialize};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+#[repr(transparent)]
+pub struct ArchivedVecTree<const N: usize, T>(ArchivedVec<T>);
+
+impl<const N: usize, T> ArchivedVecTree<N, T> {
+    pub fn as_slice_tree(&self) -> &SliceTree<N, T> {
+        self.0.as_slice().into()
+    }
+}
+
+impl<const N: usize, T> Deref for ArchivedVecTree<N, T> {
+    type Target = SliceTree<N, T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice_tree()
+    }
+}
+
+impl<const N: usize, T> AsRef<[T]> for ArchivedVecTree<N, T> {
+    fn as_ref(&self) -> &[T] {
+        self.0.as_slice()
+    }
+}
+
+impl<const N: usize, T> AsRef<SliceTree<N, T>> for ArchivedVecTree<N, T> {
+    fn as_ref(&self) -> &SliceTree<N, T> {
+        self.as_slice_tree()
+    }
+}
+
+impl<const N: usize, T: fmt::Debug> fmt::Debug for ArchivedVecTree<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ArchivedVecTree")
+            .field(&self.0.as_slice())
+            .finish()
+    }
+}
+
+unsafe impl<const N: usize, T> Portable for ArchivedVecTree<N, T> where ArchivedVec<T>: Portable {}
+
+unsafe impl<const N: usize, T, C> CheckBytes<C> for ArchivedVecTree<N, T>
+where
+    ArchivedVec<T>: CheckBytes<C>,
+    C: Fallible + ?Sized,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        unsafe { ArchivedVec::<T>::check_bytes(value.cast(), context) }
+    }
+}
+
+impl<const N: usize, T: Archive> Archive for VecTree<N, T> {
+    type Archived = ArchivedVecTree<N, T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        let out = unsafe { out.cast_unchecked::<ArchivedVec<T::Archived>>() };
+        ArchivedVec::resolve_from_slice(self.as_ref(), resolver, out);
+    }
+}
+
+impl<const N: usize, T, S> Serialize<S> for VecTree<N, T>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::serialize_from_slice(self.as_ref(), serializer)
+    }
+}
+
+impl<const N: usize, T, D> Deserialize<VecTree<N, T>, D> for ArchivedVecTree<N, T::Archived>
+where
+    T: Archive,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<VecTree<N, T>, D::Error> {
+        let nodes: Vec<T> = self.0.deserialize(deserializer)?;
+        Ok(nodes.into())
+    }
+}
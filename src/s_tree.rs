@@ -0,0 +1,203 @@
+use crate::{Index, SliceTree, VecTree};
+use alloc::vec::Vec;
+use core::array;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct STree<const N: usize, T> {
+    blocks: VecTree<N, Block<N, T>>,
+    len: usize,
+}
+
+// Stable const generics cannot spell `[T; N - 1]`, so a block has room for `N`
+// keys and only the first `N - 1` take part in the search. The spare slot, and
+// the unused slots of the last block, repeat the greatest key of their block,
+// which keeps every block sorted without changing any search result.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(C, align(64))]
+pub struct Block<const N: usize, T> {
+    keys: [T; N],
+}
+
+impl<const N: usize, T> Block<N, T> {
+    pub fn keys(&self) -> &[T] {
+        &self.keys[..N - 1]
+    }
+}
+
+impl<const N: usize, T> STree<N, T> {
+    pub fn from_sorted(mut keys: Vec<T>) -> Self
+    where
+        T: Clone,
+    {
+        const { assert!(N >= 2, "an S-tree needs an arity of at least 2") };
+
+        let len = keys.len();
+        let block_count = block_count::<N>(len);
+        let mut destinations = Vec::with_capacity(len);
+        if len != 0 {
+            collect_slots::<N>(0, block_count, len, &mut destinations);
+        }
+
+        for rank in 0..len {
+            while destinations[rank] != rank {
+                let destination = destinations[rank];
+                keys.swap(rank, destination);
+                destinations.swap(rank, destination);
+            }
+        }
+
+        let mut keys = keys.into_iter();
+        let mut blocks = VecTree::with_capacity(block_count);
+        for block in 0..block_count {
+            let key_count = (len - block * (N - 1)).min(N - 1);
+            let mut padding = None;
+            let keys = array::from_fn(|slot| {
+                if slot >= key_count {
+                    let Some(padding) = &padding else {
+                        unreachable!()
+                    };
+                    return T::clone(padding);
+                }
+                let Some(key) = keys.next() else {
+                    unreachable!()
+                };
+                if slot + 1 == key_count {
+                    padding = Some(key.clone());
+                }
+                key
+            });
+            blocks.push(Block { keys });
+        }
+
+        Self { blocks, len }
+    }
+
+    pub fn from_sorted_slice(sorted: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_sorted(sorted.to_vec())
+    }
+
+    pub fn into_sorted(self) -> Vec<T> {
+        let Self { blocks, len } = self;
+        let mut keys = Vec::with_capacity(len);
+        for (block, Block { keys: block_keys }) in blocks.into_iter().enumerate() {
+            let key_count = (len - block * (N - 1)).min(N - 1);
+            keys.extend(block_keys.into_iter().take(key_count));
+        }
+
+        let mut sources = Vec::with_capacity(len);
+        if len != 0 {
+            let block_count = block_count::<N>(len);
+            collect_slots::<N>(0, block_count, len, &mut sources);
+        }
+
+        // `sources[rank]` is the slot holding the key of that rank; gather them in
+        // place by walking each cycle of the permutation once.
+        for rank in 0..len {
+            let mut current = rank;
+            while sources[current] != rank {
+                let source = sources[current];
+                keys.swap(current, source);
+                sources[current] = current;
+                current = source;
+            }
+            sources[current] = current;
+        }
+
+        keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn height(&self) -> usize {
+        if self.blocks.is_empty() {
+            return 0;
+        }
+        let last = self.blocks.len() - 1;
+        Index::<N>::from_flattened(last).depth()
+    }
+
+    pub fn block_count(&self) -> usize {
+        self.blocks.len()
+    }
+
+    pub fn block(&self, index: Index<N>) -> Option<&[T]> {
+        let block = index.to_flattened();
+        let keys = self.blocks.get(block)?.keys();
+        let key_count = (self.len - block * (N - 1)).min(N - 1);
+        Some(&keys[..key_count])
+    }
+
+    pub fn as_slice_tree(&self) -> &SliceTree<N, Block<N, T>> {
+        self.blocks.as_slice_tree()
+    }
+
+    pub fn lower_bound(&self, key: &T) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.descend(|node| node < key)
+    }
+
+    pub fn upper_bound(&self, key: &T) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.descend(|node| node <= key)
+    }
+
+    pub fn contains(&self, key: &T) -> bool
+    where
+        T: Ord,
+    {
+        self.lower_bound(key).is_some_and(|node| node == key)
+    }
+
+    fn descend<F>(&self, go_right: F) -> Option<&T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut found = None;
+        let mut block = 0;
+        while let Some(Block { keys }) = self.blocks.get(block) {
+            let keys = &keys[..N - 1];
+            let rank = keys.iter().filter(|&key| go_right(key)).count();
+            if let Some(key) = keys.get(rank) {
+                found = Some(key);
+            }
+            block = block * N + 1 + rank;
+        }
+        found
+    }
+}
+
+fn block_count<const N: usize>(len: usize) -> usize {
+    len.div_ceil(N - 1)
+}
+
+fn collect_slots<const N: usize>(
+    block: usize,
+    block_count: usize,
+    len: usize,
+    slots: &mut Vec<usize>,
+) {
+    let first_slot = block * (N - 1);
+    let key_count = (len - first_slot).min(N - 1);
+    for n in 0..N {
+        let child = block * N + 1 + n;
+        if child < block_count {
+            collect_slots::<N>(child, block_count, len, slots);
+        }
+        if n < key_count {
+            slots.push(first_slot + n);
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use crate::{CompleteTree, Index, IndexRange, SliceTree, VecTree};
+use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
+
+pub use self::lazy::LazySegmentTree;
+
+mod lazy;
+
+pub trait Monoid<T> {
+    fn identity(&self) -> T;
+
+    fn combine(&self, a: &T, b: &T) -> T;
+}
+
+pub trait Action<T> {
+    type Tag;
+
+    fn identity(&self) -> Self::Tag;
+
+    fn apply(&self, tag: &Self::Tag, node: &T) -> T;
+
+    fn compose(&self, outer: &Self::Tag, inner: &Self::Tag) -> Self::Tag;
+}
+
+#[derive(Debug, Clone)]
+pub struct SegmentTree<T, M> {
+    tree: VecTree<2, T>,
+    len: usize,
+    height: usize,
+    monoid: M,
+}
+
+impl<T, M: Monoid<T>> SegmentTree<T, M> {
+    pub fn new(len: usize, monoid: M) -> Self {
+        let leaves: Vec<T> = (0..len).map(|_| monoid.identity()).collect();
+        Self::build(leaves, monoid)
+    }
+
+    pub fn build<I>(leaves: I, monoid: M) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let leaves: Vec<T> = leaves.into_iter().collect();
+        let len = leaves.len();
+        let height = leaf_depth(len);
+
+        let leaf_level = IndexRange::<2>::level(height);
+        let first_leaf = *leaf_level.to_flattened().start();
+        let padding = leaf_level.len() - len;
+
+        let mut tree = VecTree::with_capacity(first_leaf + leaf_level.len());
+        tree.extend((0..first_leaf).map(|_| monoid.identity()));
+        tree.extend(leaves);
+        tree.extend((0..padding).map(|_| monoid.identity()));
+
+        let mut segment_tree = Self {
+            tree,
+            len,
+            height,
+            monoid,
+        };
+        for index in (0..first_leaf).rev() {
+            segment_tree.pull(Index::from_flattened(index));
+        }
+        segment_tree
+    }
+
+    pub fn update(&mut self, index: usize, leaf: T) -> Option<T> {
+        let mut index = self.leaf(index)?;
+        let old = self.tree.replace(index, leaf)?;
+        while let Some(parent) = index.parent() {
+            self.pull(parent);
+            index = parent;
+        }
+        Some(old)
+    }
+
+    pub fn query<R>(&self, range: R) -> Option<T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = bounds(range, self.len)?;
+        let mut left = self.monoid.identity();
+        let mut right = self.monoid.identity();
+        decompose(self.height, start, end, |segment| match segment {
+            Segment::Left(index) => left = self.monoid.combine(&left, &self.tree[index]),
+            Segment::Right(index) => right = self.monoid.combine(&self.tree[index], &right),
+        });
+        Some(self.monoid.combine(&left, &right))
+    }
+
+    pub fn max_right<P>(&self, start: usize, predicate: P) -> Option<usize>
+    where
+        P: Fn(&T) -> bool,
+    {
+        max_right(&mut &*self, start, predicate)
+    }
+
+    pub fn min_left<P>(&self, end: usize, predicate: P) -> Option<usize>
+    where
+        P: Fn(&T) -> bool,
+    {
+        min_left(&mut &*self, end, predicate)
+    }
+
+    fn pull(&mut self, index: Index<2>) {
+        let (Some(left_child), Some(right_child)) = (index.left_child(), index.right_child())
+        else {
+            return;
+        };
+        let node = self
+            .monoid
+            .combine(&self.tree[left_child], &self.tree[right_child]);
+        self.tree[index] = node;
+    }
+}
+
+impl<T, M> SegmentTree<T, M> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn monoid(&self) -> &M {
+        &self.monoid
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let index = self.leaf(index)?;
+        self.tree.node(index)
+    }
+
+    pub fn all(&self) -> &T {
+        &self.tree[Index::<2>::root()]
+    }
+
+    pub fn leaves(&self) -> &[T] {
+        let start = *IndexRange::<2>::level(self.height).to_flattened().start();
+        &self.tree[start..start + self.len]
+    }
+
+    pub fn as_slice_tree(&self) -> &SliceTree<2, T> {
+        self.tree.as_slice_tree()
+    }
+
+    fn leaf(&self, index: usize) -> Option<Index<2>> {
+        if index >= self.len {
+            return None;
+        }
+        Some(level_index(self.height, index))
+    }
+}
+
+trait Search<T> {
+    type Monoid: Monoid<T>;
+
+    fn tree(&self) -> &SegmentTree<T, Self::Monoid>;
+
+    fn push(&mut self, index: Index<2>);
+}
+
+impl<T, M: Monoid<T>> Search<T> for &SegmentTree<T, M> {
+    type Monoid = M;
+
+    fn tree(&self) -> &SegmentTree<T, M> {
+        self
+    }
+
+    fn push(&mut self, _index: Index<2>) {}
+}
+
+fn max_right<T, S, P>(search: &mut S, start: usize, predicate: P) -> Option<usize>
+where
+    S: Search<T>,
+    P: Fn(&T) -> bool,
+{
+    let len = search.tree().len;
+    let height = search.tree().height;
+    if start > len {
+        return None;
+    }
+    if start == len {
+        return Some(len);
+    }
+
+    let mut index = level_index(height, start);
+    let mut sum = search.tree().monoid.identity();
+    loop {
+        while index.offset().is_multiple_of(2)
+            && let Some(parent) = index.parent()
+        {
+            index = parent;
+        }
+
+        let tree = search.tree();
+        let next = tree.monoid.combine(&sum, &tree.tree[index]);
+        if !predicate(&next) {
+            while index.depth() < height
+                && let Some(left_child) = index.left_child()
+            {
+                search.push(index);
+                let tree = search.tree();
+                let next = tree.monoid.combine(&sum, &tree.tree[left_child]);
+                if predicate(&next) {
+                    sum = next;
+                    index = level_index(left_child.depth(), left_child.offset() + 1);
+                } else {
+                    index = left_child;
+                }
+            }
+            return Some(index.offset());
+        }
+        sum = next;
+
+        let offset = index.offset() + 1;
+        if offset == IndexRange::<2>::level(index.depth()).len() {
+            return Some(len);
+        }
+        index = level_index(index.depth(), offset);
+    }
+}
+
+fn min_left<T, S, P>(search: &mut S, end: usize, predicate: P) -> Option<usize>
+where
+    S: Search<T>,
+    P: Fn(&T) -> bool,
+{
+    let len = search.tree().len;
+    let height = search.tree().height;
+    if end > len {
+        return None;
+    }
+    if end == 0 {
+        return Some(0);
+    }
+
+    let mut index = level_index(height, end - 1);
+    let mut sum = search.tree().monoid.identity();
+    loop {
+        while index.offset() % 2 == 1
+            && let Some(parent) = index.parent()
+        {
+            index = parent;
+        }
+
+        let tree = search.tree();
+        let next = tree.monoid.combine(&tree.tree[index], &sum);
+        if !predicate(&next) {
+            while index.depth() < height
+                && let Some(right_child) = index.right_child()
+            {
+                search.push(index);
+                let tree = search.tree();
+                let next = tree.monoid.combine(&tree.tree[right_child], &sum);
+                if predicate(&next) {
+                    sum = next;
+                    index = level_index(right_child.depth(), right_child.offset() - 1);
+                } else {
+                    index = right_child;
+                }
+            }
+            return Some(index.offset() + 1);
+        }
+        sum = next;
+
+        if index.offset() == 0 {
+            return Some(0);
+        }
+        index = level_index(index.depth(), index.offset() - 1);
+    }
+}
+
+enum Segment {
+    Left(Index<2>),
+    Right(Index<2>),
+}
+
+fn decompose<F>(height: usize, mut start: usize, mut end: usize, mut visit: F)
+where
+    F: FnMut(Segment),
+{
+    let mut depth = height;
+    while start < end {
+        if start % 2 == 1 {
+            visit(Segment::Left(level_index(depth, start)));
+            start += 1;
+        }
+        if end % 2 == 1 {
+            end -= 1;
+            visit(Segment::Right(level_index(depth, end)));
+        }
+        start /= 2;
+        end /= 2;
+        depth = depth.saturating_sub(1);
+    }
+}
+
+fn bounds<R>(range: R, len: usize) -> Option<(usize, usize)>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    if start > end || end > len {
+        return None;
+    }
+    Some((start, end))
+}
+
+fn leaf_depth(len: usize) -> usize {
+    len.max(1).next_power_of_two().trailing_zeros() as usize
+}
+
+fn level_index(depth: usize, offset: usize) -> Index<2> {
+    let Some(index) = Index::new(depth, offset) else {
+        unreachable!()
+    };
+    index
+}
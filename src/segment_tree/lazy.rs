@@ -0,0 +1,175 @@
+use super::{Action, Monoid, Search, Segment, SegmentTree, bounds, decompose, level_index};
+use crate::{Index, VecTree};
+use core::mem;
+use core::ops::RangeBounds;
+
+#[derive(Debug, Clone)]
+pub struct LazySegmentTree<T, M, A: Action<T>> {
+    tree: SegmentTree<T, M>,
+    tags: VecTree<2, A::Tag>,
+    action: A,
+}
+
+impl<T, M: Monoid<T>, A: Action<T>> LazySegmentTree<T, M, A> {
+    pub fn new(len: usize, monoid: M, action: A) -> Self {
+        Self::from_segment_tree(SegmentTree::new(len, monoid), action)
+    }
+
+    pub fn build<I>(leaves: I, monoid: M, action: A) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from_segment_tree(SegmentTree::build(leaves, monoid), action)
+    }
+
+    pub fn get(&mut self, index: usize) -> Option<&T> {
+        if index >= self.len() {
+            return None;
+        }
+        self.push_ancestors(index);
+        self.tree.get(index)
+    }
+
+    pub fn update(&mut self, index: usize, leaf: T) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        self.push_ancestors(index);
+        self.tree.update(index, leaf)
+    }
+
+    pub fn query<R>(&mut self, range: R) -> Option<T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = bounds(range, self.len())?;
+        self.push_boundaries(start, end);
+        self.tree.query(start..end)
+    }
+
+    pub fn apply<R>(&mut self, range: R, tag: A::Tag) -> Option<()>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = bounds(range, self.len())?;
+        if start == end {
+            return Some(());
+        }
+        self.push_boundaries(start, end);
+        decompose(self.tree.height, start, end, |segment| {
+            let (Segment::Left(index) | Segment::Right(index)) = segment;
+            self.apply_at(index, &tag);
+        });
+        self.pull_boundaries(start, end);
+        Some(())
+    }
+
+    pub fn max_right<P>(&mut self, start: usize, predicate: P) -> Option<usize>
+    where
+        P: Fn(&T) -> bool,
+    {
+        if start < self.len() {
+            self.push_ancestors(start);
+        }
+        super::max_right(self, start, predicate)
+    }
+
+    pub fn min_left<P>(&mut self, end: usize, predicate: P) -> Option<usize>
+    where
+        P: Fn(&T) -> bool,
+    {
+        if end > 0 && end <= self.len() {
+            self.push_ancestors(end - 1);
+        }
+        super::min_left(self, end, predicate)
+    }
+
+    fn ancestor(&self, offset: usize, height: usize) -> Index<2> {
+        level_index(self.tree.height - height, offset >> height)
+    }
+
+    fn push_ancestors(&mut self, offset: usize) {
+        for height in (1..=self.tree.height).rev() {
+            self.push(self.ancestor(offset, height));
+        }
+    }
+
+    fn push_boundaries(&mut self, start: usize, end: usize) {
+        for height in (1..=self.tree.height).rev() {
+            if (start >> height) << height != start {
+                self.push(self.ancestor(start, height));
+            }
+            if (end >> height) << height != end {
+                self.push(self.ancestor(end - 1, height));
+            }
+        }
+    }
+
+    fn pull_boundaries(&mut self, start: usize, end: usize) {
+        for height in 1..=self.tree.height {
+            if (start >> height) << height != start {
+                self.tree.pull(self.ancestor(start, height));
+            }
+            if (end >> height) << height != end {
+                self.tree.pull(self.ancestor(end - 1, height));
+            }
+        }
+    }
+
+    fn apply_at(&mut self, index: Index<2>, tag: &A::Tag) {
+        self.tree.tree[index] = self.action.apply(tag, &self.tree.tree[index]);
+        if index.depth() < self.tree.height {
+            self.tags[index] = self.action.compose(tag, &self.tags[index]);
+        }
+    }
+
+    fn push(&mut self, index: Index<2>) {
+        let (Some(left_child), Some(right_child)) = (index.left_child(), index.right_child())
+        else {
+            return;
+        };
+        let tag = mem::replace(&mut self.tags[index], self.action.identity());
+        self.apply_at(left_child, &tag);
+        self.apply_at(right_child, &tag);
+    }
+}
+
+impl<T, M, A: Action<T>> LazySegmentTree<T, M, A> {
+    pub fn from_segment_tree(tree: SegmentTree<T, M>, action: A) -> Self {
+        let internal = tree.tree.len() / 2;
+        let tags = (0..internal).map(|_| action.identity()).collect();
+        Self { tree, tags, action }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    pub fn monoid(&self) -> &M {
+        self.tree.monoid()
+    }
+
+    pub fn action(&self) -> &A {
+        &self.action
+    }
+
+    pub fn all(&self) -> &T {
+        self.tree.all()
+    }
+}
+
+impl<T, M: Monoid<T>, A: Action<T>> Search<T> for LazySegmentTree<T, M, A> {
+    type Monoid = M;
+
+    fn tree(&self) -> &SegmentTree<T, M> {
+        &self.tree
+    }
+
+    fn push(&mut self, index: Index<2>) {
+        LazySegmentTree::push(self, index);
+    }
+}
@@ -0,0 +1,371 @@
+use crate::{ArrayTree, Index, IndexRange, SliceTree};
+use ::serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use ::serde::ser::{Serialize, SerializeStruct, Serializer};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::RangeInclusive;
+
+#[cfg(feature = "alloc")]
+use crate::VecTree;
+#[cfg(feature = "alloc")]
+use crate::heap::{Compare, DaryHeap, IndexedHeap, is_heap};
+#[cfg(feature = "alloc")]
+use crate::segment_tree::{Monoid, SegmentTree};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+pub mod flattened {
+    use crate::Index;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<const N: usize, S>(index: &Index<N>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        index.to_flattened().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, const N: usize, D>(deserializer: D) -> Result<Index<N>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        usize::deserialize(deserializer).map(Index::from_flattened)
+    }
+}
+
+const INDEX_FIELDS: &[&str] = &["depth", "offset"];
+
+impl<const N: usize> Serialize for Index<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Index", INDEX_FIELDS.len())?;
+        state.serialize_field("depth", &self.depth())?;
+        state.serialize_field("offset", &self.offset())?;
+        state.end()
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Index<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("Index", INDEX_FIELDS, IndexVisitor)
+    }
+}
+
+struct IndexVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for IndexVisitor<N> {
+    type Value = Index<N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("struct Index")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let Some(depth) = seq.next_element()? else {
+            return Err(de::Error::invalid_length(0, &self));
+        };
+        let Some(offset) = seq.next_element()? else {
+            return Err(de::Error::invalid_length(1, &self));
+        };
+        Index::try_new(depth, offset).map_err(de::Error::custom)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut depth = None;
+        let mut offset = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                IndexField::Depth if depth.is_some() => {
+                    return Err(de::Error::duplicate_field("depth"));
+                }
+                IndexField::Offset if offset.is_some() => {
+                    return Err(de::Error::duplicate_field("offset"));
+                }
+                IndexField::Depth => depth = Some(map.next_value()?),
+                IndexField::Offset => offset = Some(map.next_value()?),
+            }
+        }
+        let Some(depth) = depth else {
+            return Err(de::Error::missing_field("depth"));
+        };
+        let Some(offset) = offset else {
+            return Err(de::Error::missing_field("offset"));
+        };
+        Index::try_new(depth, offset).map_err(de::Error::custom)
+    }
+}
+
+enum IndexField {
+    Depth,
+    Offset,
+}
+
+impl<'de> Deserialize<'de> for IndexField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(IndexFieldVisitor)
+    }
+}
+
+struct IndexFieldVisitor;
+
+impl Visitor<'_> for IndexFieldVisitor {
+    type Value = IndexField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("`depth` or `offset`")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match value {
+            0 => Ok(IndexField::Depth),
+            1 => Ok(IndexField::Offset),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(value),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match value {
+            "depth" => Ok(IndexField::Depth),
+            "offset" => Ok(IndexField::Offset),
+            _ => Err(de::Error::unknown_field(value, INDEX_FIELDS)),
+        }
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match value {
+            b"depth" => Ok(IndexField::Depth),
+            b"offset" => Ok(IndexField::Offset),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Bytes(value),
+                &self,
+            )),
+        }
+    }
+}
+
+impl<const N: usize> Serialize for IndexRange<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // An exhausted range still reports its last bounds, so empty ranges are
+        // written in the canonical form of `IndexRange::empty`.
+        let range = if self.is_empty() {
+            Self::empty().to_flattened()
+        } else {
+            self.to_flattened()
+        };
+        range.serialize(serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for IndexRange<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let range = RangeInclusive::<usize>::deserialize(deserializer)?;
+        if range.is_empty() {
+            return Ok(Self::empty());
+        }
+        if *range.start() == usize::MIN && *range.end() == usize::MAX {
+            return Err(de::Error::custom("index range length overflows `usize`"));
+        }
+        Ok(Self::from_flattened(range))
+    }
+}
+
+impl<const N: usize, T: Serialize> Serialize for SliceTree<N, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let nodes: &[T] = self.into();
+        nodes.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize, T: Serialize> Serialize for VecTree<N, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_slice_tree().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, const N: usize, T: Deserialize<'de>> Deserialize<'de> for VecTree<N, T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<const N: usize, T: Serialize, const CAP: usize> Serialize for ArrayTree<N, T, CAP> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_slice_tree().serialize(serializer)
+    }
+}
+
+impl<'de, const N: usize, T: Deserialize<'de>, const CAP: usize> Deserialize<'de>
+    for ArrayTree<N, T, CAP>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ArrayTreeVisitor(PhantomData))
+    }
+}
+
+struct ArrayTreeVisitor<const N: usize, T, const CAP: usize>(PhantomData<T>);
+
+impl<'de, const N: usize, T: Deserialize<'de>, const CAP: usize> Visitor<'de>
+    for ArrayTreeVisitor<N, T, CAP>
+{
+    type Value = ArrayTree<N, T, CAP>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a sequence of at most {CAP} nodes")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut tree = ArrayTree::new();
+        while let Some(node) = seq.next_element()? {
+            if tree.push(node).is_err() {
+                return Err(de::Error::invalid_length(CAP + 1, &self));
+            }
+        }
+        Ok(tree)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize, T: Serialize, C> Serialize for DaryHeap<N, T, C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_slice_tree().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, const N: usize, T, C> Deserialize<'de> for DaryHeap<N, T, C>
+where
+    T: Deserialize<'de>,
+    C: Compare<T> + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let nodes = Vec::deserialize(deserializer)?;
+        let compare = C::default();
+        if !is_heap::<N, T, C>(nodes.as_slice().into(), &compare) {
+            return Err(de::Error::custom("nodes do not satisfy the heap property"));
+        }
+        Ok(Self::from_vec_with_comparator(nodes, compare))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize, T: Serialize, C> Serialize for IndexedHeap<N, T, C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_slice_tree().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, const N: usize, T, C> Deserialize<'de> for IndexedHeap<N, T, C>
+where
+    T: Deserialize<'de>,
+    C: Compare<T> + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let nodes: Vec<(usize, T)> = Vec::deserialize(deserializer)?;
+        let compare = C::default();
+        let compare_priority = |a: &(usize, T), b: &(usize, T)| compare.compare(&a.1, &b.1);
+        if !is_heap::<N, _, _>(nodes.as_slice().into(), &compare_priority) {
+            return Err(de::Error::custom("nodes do not satisfy the heap property"));
+        }
+
+        // Pushing the nodes of a valid heap in level order never sifts, so the
+        // layout is kept as serialized.
+        let mut heap = Self::with_capacity_and_comparator(nodes.len(), compare);
+        for (key, priority) in nodes {
+            if heap.contains(key) {
+                return Err(de::Error::custom(format_args!("duplicate key {key}")));
+            }
+            heap.push(key, priority);
+        }
+        Ok(heap)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Serialize, M> Serialize for SegmentTree<T, M> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.leaves().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T, M> Deserialize<'de> for SegmentTree<T, M>
+where
+    T: Deserialize<'de>,
+    M: Monoid<T> + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let leaves: Vec<T> = Vec::deserialize(deserializer)?;
+        Ok(Self::build(leaves, M::default()))
+    }
+}
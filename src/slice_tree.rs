@@ -1,12 +1,26 @@
-use self::traverse::{InOrder, InOrderMut, PostOrder, PostOrderMut, PreOrder, PreOrderMut};
+pub use self::iter_levels::{IterLevels, IterLevelsMut};
+pub use self::subtree::{Levels, Subtree};
+
+use self::traverse::{
+    InOrder, InOrderIndexed, InOrderIndexedMut, InOrderMut, LevelOrderIndexed,
+    LevelOrderIndexedMut, Nodes, PostOrder, PostOrderIndexed, PostOrderIndexedMut, PostOrderMut,
+    PreOrder, PreOrderIndexed, PreOrderIndexedMut, PreOrderMut,
+};
+use crate::index::{self, traverse as indices};
 use crate::{CompleteBinaryTree, CompleteTree, Index, IndexRange};
 use core::mem;
-use core::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut, RangeBounds};
 use core::ptr;
 use core::slice::{Iter, IterMut};
 
 pub mod traverse;
 
+mod eytzinger;
+mod iter_levels;
+mod subtree;
+
+pub type Ancestors<'a, const N: usize, T> = Nodes<'a, index::Ancestors<N>, T>;
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct SliceTree<const N: usize, T>([T]);
@@ -114,6 +128,86 @@ impl<const N: usize, T> CompleteTree<N> for SliceTree<N, T> {
     where
         Self: 'a;
 
+    type LevelOrderIndexed<'a>
+        = LevelOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexedMut<'a>
+        = LevelOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type PreOrderIndexed<'a>
+        = PreOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type PreOrderIndexedMut<'a>
+        = PreOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrderIndexed<'a>
+        = PostOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrderIndexedMut<'a>
+        = PostOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type Ancestors<'a>
+        = Ancestors<'a, N, T>
+    where
+        Self: 'a;
+
+    type IterSiblings<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterSiblingsMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type Subtree<'a>
+        = Subtree<'a, Index<N>, T>
+    where
+        Self: 'a;
+
+    type IterLeaves<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterLeavesMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterInternal<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterInternalMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevels<'a>
+        = IterLevels<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevelsMut<'a>
+        = IterLevelsMut<'a, T>
+    where
+        Self: 'a;
+
     fn len(&self) -> usize {
         CompleteTree::<N>::len(self.as_ref())
     }
@@ -150,6 +244,18 @@ impl<const N: usize, T> CompleteTree<N> for SliceTree<N, T> {
         CompleteTree::<N>::last_mut(self.as_mut())
     }
 
+    fn ancestors(&self, index: Index<N>) -> Option<Self::Ancestors<'_>> {
+        CompleteTree::<N>::ancestors(self.as_ref(), index)
+    }
+
+    fn iter_siblings(&self, index: Index<N>) -> Option<Self::IterSiblings<'_>> {
+        CompleteTree::<N>::iter_siblings(self.as_ref(), index)
+    }
+
+    fn iter_siblings_mut(&mut self, index: Index<N>) -> Option<Self::IterSiblingsMut<'_>> {
+        CompleteTree::<N>::iter_siblings_mut(self.as_mut(), index)
+    }
+
     fn iter_children(&self, index: Index<N>) -> Option<Self::IterChildren<'_>> {
         CompleteTree::<N>::iter_children(self.as_ref(), index)
     }
@@ -158,6 +264,10 @@ impl<const N: usize, T> CompleteTree<N> for SliceTree<N, T> {
         CompleteTree::<N>::iter_children_mut(self.as_mut(), index)
     }
 
+    fn subtree(&self, index: Index<N>) -> Option<Self::Subtree<'_>> {
+        CompleteTree::<N>::subtree(self.as_ref(), index)
+    }
+
     fn iter_level(&self, depth: usize) -> Option<Self::IterLevel<'_>> {
         CompleteTree::<N>::iter_level(self.as_ref(), depth)
     }
@@ -166,6 +276,36 @@ impl<const N: usize, T> CompleteTree<N> for SliceTree<N, T> {
         CompleteTree::<N>::iter_level_mut(self.as_mut(), depth)
     }
 
+    fn iter_leaves(&self) -> Self::IterLeaves<'_> {
+        CompleteTree::<N>::iter_leaves(self.as_ref())
+    }
+
+    fn iter_leaves_mut(&mut self) -> Self::IterLeavesMut<'_> {
+        CompleteTree::<N>::iter_leaves_mut(self.as_mut())
+    }
+
+    fn iter_internal(&self) -> Self::IterInternal<'_> {
+        CompleteTree::<N>::iter_internal(self.as_ref())
+    }
+
+    fn iter_internal_mut(&mut self) -> Self::IterInternalMut<'_> {
+        CompleteTree::<N>::iter_internal_mut(self.as_mut())
+    }
+
+    fn iter_levels<R>(&self, depths: R) -> Self::IterLevels<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        CompleteTree::<N>::iter_levels(self.as_ref(), depths)
+    }
+
+    fn iter_levels_mut<R>(&mut self, depths: R) -> Self::IterLevelsMut<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        CompleteTree::<N>::iter_levels_mut(self.as_mut(), depths)
+    }
+
     fn traverse_level_order(&self) -> Self::LevelOrder<'_> {
         CompleteTree::<N>::traverse_level_order(self.as_ref())
     }
@@ -189,6 +329,46 @@ impl<const N: usize, T> CompleteTree<N> for SliceTree<N, T> {
     fn traverse_post_order_mut(&mut self) -> Self::PostOrderMut<'_> {
         CompleteTree::<N>::traverse_post_order_mut(self.as_mut())
     }
+
+    fn traverse_pre_order_from(&self, index: Index<N>) -> Option<Self::PreOrder<'_>> {
+        CompleteTree::<N>::traverse_pre_order_from(self.as_ref(), index)
+    }
+
+    fn traverse_pre_order_from_mut(&mut self, index: Index<N>) -> Option<Self::PreOrderMut<'_>> {
+        CompleteTree::<N>::traverse_pre_order_from_mut(self.as_mut(), index)
+    }
+
+    fn traverse_post_order_from(&self, index: Index<N>) -> Option<Self::PostOrder<'_>> {
+        CompleteTree::<N>::traverse_post_order_from(self.as_ref(), index)
+    }
+
+    fn traverse_post_order_from_mut(&mut self, index: Index<N>) -> Option<Self::PostOrderMut<'_>> {
+        CompleteTree::<N>::traverse_post_order_from_mut(self.as_mut(), index)
+    }
+
+    fn traverse_level_order_indexed(&self) -> Self::LevelOrderIndexed<'_> {
+        CompleteTree::<N>::traverse_level_order_indexed(self.as_ref())
+    }
+
+    fn traverse_level_order_indexed_mut(&mut self) -> Self::LevelOrderIndexedMut<'_> {
+        CompleteTree::<N>::traverse_level_order_indexed_mut(self.as_mut())
+    }
+
+    fn traverse_pre_order_indexed(&self) -> Self::PreOrderIndexed<'_> {
+        CompleteTree::<N>::traverse_pre_order_indexed(self.as_ref())
+    }
+
+    fn traverse_pre_order_indexed_mut(&mut self) -> Self::PreOrderIndexedMut<'_> {
+        CompleteTree::<N>::traverse_pre_order_indexed_mut(self.as_mut())
+    }
+
+    fn traverse_post_order_indexed(&self) -> Self::PostOrderIndexed<'_> {
+        CompleteTree::<N>::traverse_post_order_indexed(self.as_ref())
+    }
+
+    fn traverse_post_order_indexed_mut(&mut self) -> Self::PostOrderIndexedMut<'_> {
+        CompleteTree::<N>::traverse_post_order_indexed_mut(self.as_mut())
+    }
 }
 
 impl<T> CompleteBinaryTree for SliceTree<2, T> {
@@ -202,6 +382,16 @@ impl<T> CompleteBinaryTree for SliceTree<2, T> {
     where
         Self: 'a;
 
+    type InOrderIndexed<'a>
+        = InOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderIndexedMut<'a>
+        = InOrderIndexedMut<'a, T>
+    where
+        Self: 'a;
+
     fn traverse_in_order(&self) -> InOrder<'_, T> {
         CompleteBinaryTree::traverse_in_order(self.as_ref())
     }
@@ -209,6 +399,22 @@ impl<T> CompleteBinaryTree for SliceTree<2, T> {
     fn traverse_in_order_mut(&mut self) -> InOrderMut<'_, T> {
         CompleteBinaryTree::traverse_in_order_mut(self.as_mut())
     }
+
+    fn traverse_in_order_from(&self, index: Index<2>) -> Option<Self::InOrder<'_>> {
+        CompleteBinaryTree::traverse_in_order_from(self.as_ref(), index)
+    }
+
+    fn traverse_in_order_from_mut(&mut self, index: Index<2>) -> Option<Self::InOrderMut<'_>> {
+        CompleteBinaryTree::traverse_in_order_from_mut(self.as_mut(), index)
+    }
+
+    fn traverse_in_order_indexed(&self) -> Self::InOrderIndexed<'_> {
+        CompleteBinaryTree::traverse_in_order_indexed(self.as_ref())
+    }
+
+    fn traverse_in_order_indexed_mut(&mut self) -> Self::InOrderIndexedMut<'_> {
+        CompleteBinaryTree::traverse_in_order_indexed_mut(self.as_mut())
+    }
 }
 
 impl<const N: usize, T, I> core::ops::Index<I> for SliceTree<N, T>
@@ -284,6 +490,86 @@ impl<const N: usize, T> CompleteTree<N> for [T] {
     where
         Self: 'a;
 
+    type LevelOrderIndexed<'a>
+        = LevelOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexedMut<'a>
+        = LevelOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type PreOrderIndexed<'a>
+        = PreOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type PreOrderIndexedMut<'a>
+        = PreOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrderIndexed<'a>
+        = PostOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrderIndexedMut<'a>
+        = PostOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type Ancestors<'a>
+        = Ancestors<'a, N, T>
+    where
+        Self: 'a;
+
+    type IterSiblings<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterSiblingsMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type Subtree<'a>
+        = Subtree<'a, Index<N>, T>
+    where
+        Self: 'a;
+
+    type IterLeaves<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterLeavesMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterInternal<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterInternalMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevels<'a>
+        = IterLevels<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevelsMut<'a>
+        = IterLevelsMut<'a, T>
+    where
+        Self: 'a;
+
     fn len(&self) -> usize {
         self.len()
     }
@@ -342,6 +628,29 @@ impl<const N: usize, T> CompleteTree<N> for [T] {
         self.last_mut()
     }
 
+    fn ancestors(&self, index: Index<N>) -> Option<Self::Ancestors<'_>> {
+        if index.to_flattened() >= self.len() {
+            return None;
+        }
+        Some(Ancestors::with_indices(index.ancestors(), self))
+    }
+
+    fn iter_siblings(&self, index: Index<N>) -> Option<Self::IterSiblings<'_>> {
+        if index.to_flattened() >= self.len() {
+            return None;
+        }
+        let siblings = index.iter_siblings().cap(self.len()).to_flattened();
+        self.get(siblings).map(Self::iter)
+    }
+
+    fn iter_siblings_mut(&mut self, index: Index<N>) -> Option<Self::IterSiblingsMut<'_>> {
+        if index.to_flattened() >= self.len() {
+            return None;
+        }
+        let siblings = index.iter_siblings().cap(self.len()).to_flattened();
+        self.get_mut(siblings).map(Self::iter_mut)
+    }
+
     fn iter_children(&self, index: Index<N>) -> Option<Self::IterChildren<'_>> {
         if index.to_flattened() >= self.len() {
             return None;
@@ -358,6 +667,13 @@ impl<const N: usize, T> CompleteTree<N> for [T] {
         self.get_mut(children).map(Self::iter_mut)
     }
 
+    fn subtree(&self, index: Index<N>) -> Option<Self::Subtree<'_>> {
+        if index.to_flattened() >= self.len() {
+            return None;
+        }
+        Some(Subtree::new(index, self))
+    }
+
     fn iter_level(&self, depth: usize) -> Option<Self::IterLevel<'_>> {
         if depth > CompleteTree::<N>::height(self) {
             return None;
@@ -374,6 +690,40 @@ impl<const N: usize, T> CompleteTree<N> for [T] {
         self.get_mut(level).map(Self::iter_mut)
     }
 
+    fn iter_leaves(&self) -> Self::IterLeaves<'_> {
+        let internal = CompleteTree::<N>::internal_count(self);
+        self[internal..].iter()
+    }
+
+    fn iter_leaves_mut(&mut self) -> Self::IterLeavesMut<'_> {
+        let internal = CompleteTree::<N>::internal_count(self);
+        self[internal..].iter_mut()
+    }
+
+    fn iter_internal(&self) -> Self::IterInternal<'_> {
+        let internal = CompleteTree::<N>::internal_count(self);
+        self[..internal].iter()
+    }
+
+    fn iter_internal_mut(&mut self) -> Self::IterInternalMut<'_> {
+        let internal = CompleteTree::<N>::internal_count(self);
+        self[..internal].iter_mut()
+    }
+
+    fn iter_levels<R>(&self, depths: R) -> Self::IterLevels<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        IterLevels::new(N, depths, self)
+    }
+
+    fn iter_levels_mut<R>(&mut self, depths: R) -> Self::IterLevelsMut<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        IterLevelsMut::new(N, depths, self)
+    }
+
     fn traverse_level_order(&self) -> Self::LevelOrder<'_> {
         self.iter()
     }
@@ -397,6 +747,68 @@ impl<const N: usize, T> CompleteTree<N> for [T] {
     fn traverse_post_order_mut(&mut self) -> Self::PostOrderMut<'_> {
         PostOrderMut::<N, T>::new(self)
     }
+
+    fn traverse_pre_order_from(&self, index: Index<N>) -> Option<Self::PreOrder<'_>> {
+        if index.to_flattened() >= self.len() {
+            return None;
+        }
+        let indices = indices::PreOrder::new_from(index, self.len());
+        Some(PreOrder::with_indices(indices, self))
+    }
+
+    fn traverse_pre_order_from_mut(&mut self, index: Index<N>) -> Option<Self::PreOrderMut<'_>> {
+        if index.to_flattened() >= self.len() {
+            return None;
+        }
+        let indices = indices::PreOrder::new_from(index, self.len());
+        Some(PreOrderMut::with_indices(indices, self))
+    }
+
+    fn traverse_post_order_from(&self, index: Index<N>) -> Option<Self::PostOrder<'_>> {
+        if index.to_flattened() >= self.len() {
+            return None;
+        }
+        let indices = indices::PostOrder::new_from(index, self.len());
+        Some(PostOrder::with_indices(indices, self))
+    }
+
+    fn traverse_post_order_from_mut(&mut self, index: Index<N>) -> Option<Self::PostOrderMut<'_>> {
+        if index.to_flattened() >= self.len() {
+            return None;
+        }
+        let indices = indices::PostOrder::new_from(index, self.len());
+        Some(PostOrderMut::with_indices(indices, self))
+    }
+
+    fn traverse_level_order_indexed(&self) -> Self::LevelOrderIndexed<'_> {
+        let indices = indices::LevelOrder::new(self.len());
+        LevelOrderIndexed::<N, T>::new(indices, self)
+    }
+
+    fn traverse_level_order_indexed_mut(&mut self) -> Self::LevelOrderIndexedMut<'_> {
+        let indices = indices::LevelOrder::new(self.len());
+        LevelOrderIndexedMut::<N, T>::new(indices, self)
+    }
+
+    fn traverse_pre_order_indexed(&self) -> Self::PreOrderIndexed<'_> {
+        let indices = indices::PreOrder::new(self.len());
+        PreOrderIndexed::<N, T>::new(indices, self)
+    }
+
+    fn traverse_pre_order_indexed_mut(&mut self) -> Self::PreOrderIndexedMut<'_> {
+        let indices = indices::PreOrder::new(self.len());
+        PreOrderIndexedMut::<N, T>::new(indices, self)
+    }
+
+    fn traverse_post_order_indexed(&self) -> Self::PostOrderIndexed<'_> {
+        let indices = indices::PostOrder::new(self.len());
+        PostOrderIndexed::<N, T>::new(indices, self)
+    }
+
+    fn traverse_post_order_indexed_mut(&mut self) -> Self::PostOrderIndexedMut<'_> {
+        let indices = indices::PostOrder::new(self.len());
+        PostOrderIndexedMut::<N, T>::new(indices, self)
+    }
 }
 
 impl<T> CompleteBinaryTree for [T] {
@@ -410,6 +822,16 @@ impl<T> CompleteBinaryTree for [T] {
     where
         Self: 'a;
 
+    type InOrderIndexed<'a>
+        = InOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderIndexedMut<'a>
+        = InOrderIndexedMut<'a, T>
+    where
+        Self: 'a;
+
     fn traverse_in_order(&self) -> InOrder<'_, T> {
         InOrder::new(self)
     }
@@ -417,6 +839,32 @@ impl<T> CompleteBinaryTree for [T] {
     fn traverse_in_order_mut(&mut self) -> InOrderMut<'_, T> {
         InOrderMut::new(self)
     }
+
+    fn traverse_in_order_from(&self, index: Index<2>) -> Option<Self::InOrder<'_>> {
+        if index.to_flattened() >= self.len() {
+            return None;
+        }
+        let indices = indices::InOrder::new_from(index, self.len());
+        Some(InOrder::with_indices(indices, self))
+    }
+
+    fn traverse_in_order_from_mut(&mut self, index: Index<2>) -> Option<Self::InOrderMut<'_>> {
+        if index.to_flattened() >= self.len() {
+            return None;
+        }
+        let indices = indices::InOrder::new_from(index, self.len());
+        Some(InOrderMut::with_indices(indices, self))
+    }
+
+    fn traverse_in_order_indexed(&self) -> InOrderIndexed<'_, T> {
+        let indices = indices::InOrder::new(self.len());
+        InOrderIndexed::new(indices, self)
+    }
+
+    fn traverse_in_order_indexed_mut(&mut self) -> InOrderIndexedMut<'_, T> {
+        let indices = indices::InOrder::new(self.len());
+        InOrderIndexedMut::new(indices, self)
+    }
 }
 
 impl<const N: usize, T> core::ops::Index<Index<N>> for [T] {
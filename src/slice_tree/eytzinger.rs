@@ -0,0 +1,142 @@
+use super::SliceTree;
+use crate::{CompleteBinaryTree, CompleteTree, Index};
+
+impl<T> SliceTree<2, T> {
+    pub fn from_sorted(nodes: &mut [T]) -> &mut Self {
+        let tree_len = nodes.len();
+        for depth in (1..=height(tree_len)).rev() {
+            let (prefix, leaves) = level_bounds(depth, tree_len);
+            let interleaved = (2 * leaves).min(prefix);
+            unshuffle(&mut nodes[..interleaved]);
+            nodes[interleaved - leaves..prefix].rotate_left(leaves);
+        }
+        nodes.into()
+    }
+
+    pub fn from_sorted_into<'a>(sorted: &[T], buffer: &'a mut [T]) -> Option<&'a mut Self>
+    where
+        T: Clone,
+    {
+        if sorted.len() != buffer.len() {
+            return None;
+        }
+        let tree: &mut Self = buffer.into();
+        for (node, value) in tree.traverse_in_order_mut().zip(sorted) {
+            node.clone_from(value);
+        }
+        Some(tree)
+    }
+
+    pub fn to_sorted(&mut self) -> &mut [T] {
+        let nodes: &mut [T] = self.into();
+        let tree_len = nodes.len();
+        for depth in 1..=height(tree_len) {
+            let (prefix, leaves) = level_bounds(depth, tree_len);
+            let interleaved = (2 * leaves).min(prefix);
+            nodes[interleaved - leaves..prefix].rotate_right(leaves);
+            shuffle(&mut nodes[..interleaved]);
+        }
+        nodes
+    }
+
+    pub fn to_sorted_into(&self, buffer: &mut [T]) -> Option<()>
+    where
+        T: Clone,
+    {
+        if self.len() != buffer.len() {
+            return None;
+        }
+        for (value, node) in buffer.iter_mut().zip(self.traverse_in_order()) {
+            value.clone_from(node);
+        }
+        Some(())
+    }
+
+    pub fn lower_bound(&self, value: &T) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.descend(|node| node < value)
+    }
+
+    pub fn upper_bound(&self, value: &T) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.descend(|node| node <= value)
+    }
+
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: Ord,
+    {
+        self.lower_bound(value).is_some_and(|node| node == value)
+    }
+
+    fn descend<F>(&self, go_right: F) -> Option<&T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        // Walks 1-based positions so that the answer can be recovered from the
+        // trailing right turns of the final position.
+        let nodes: &[T] = self.into();
+        let mut position = 1;
+        while position <= nodes.len() {
+            let node = unsafe { nodes.get_unchecked(position - 1) };
+            position = 2 * position + go_right(node) as usize;
+        }
+        let position = position
+            .checked_shr(position.trailing_ones() + 1)
+            .unwrap_or(0);
+        if position == 0 {
+            return None;
+        }
+        Some(&nodes[position - 1])
+    }
+}
+
+fn height(tree_len: usize) -> usize {
+    if tree_len == 0 {
+        return 0;
+    }
+    Index::<2>::from_flattened(tree_len - 1).depth()
+}
+
+fn level_bounds(depth: usize, tree_len: usize) -> (usize, usize) {
+    let above = (1 << depth) - 1;
+    let prefix = tree_len.min(2 * above + 1);
+    let leaves = prefix - above;
+    (prefix, leaves)
+}
+
+fn unshuffle<T>(nodes: &mut [T]) {
+    let len = nodes.len();
+    if len < 4 {
+        if len >= 2 {
+            nodes.swap(0, 1);
+        }
+        return;
+    }
+    let mid = (len / 2) & !1;
+    let (left, right) = nodes.split_at_mut(mid);
+    unshuffle(left);
+    unshuffle(right);
+    let odd_right = (len - mid) / 2;
+    nodes[mid / 2..mid + odd_right].rotate_left(mid / 2);
+}
+
+fn shuffle<T>(nodes: &mut [T]) {
+    let len = nodes.len();
+    if len < 4 {
+        if len >= 2 {
+            nodes.swap(0, 1);
+        }
+        return;
+    }
+    let mid = (len / 2) & !1;
+    let odd = len / 2;
+    nodes[mid / 2..odd + mid / 2].rotate_right(mid / 2);
+    let (left, right) = nodes.split_at_mut(mid);
+    shuffle(left);
+    shuffle(right);
+}
@@ -0,0 +1,168 @@
+use crate::index::{level_start, split_flattened};
+use core::iter::FusedIterator;
+use core::mem;
+use core::ops::{Bound, Range, RangeBounds, RangeInclusive};
+use core::slice::{Iter, IterMut};
+
+#[derive(Debug, Clone)]
+pub struct IterLevels<'a, T> {
+    arity: usize,
+    depths: Range<usize>,
+    tree: &'a [T],
+}
+
+impl<'a, T> IterLevels<'a, T> {
+    pub(crate) fn new<R>(arity: usize, depths: R, tree: &'a [T]) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let depths = clamp_depths(arity, depths, tree.len());
+        Self {
+            arity,
+            depths,
+            tree,
+        }
+    }
+
+    fn level(&self, depth: usize) -> Iter<'a, T> {
+        let level = level(self.arity, depth, self.tree.len());
+        self.tree[level].iter()
+    }
+}
+
+impl<'a, T> Iterator for IterLevels<'a, T> {
+    type Item = Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let depth = self.depths.next()?;
+        Some(self.level(depth))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.depths.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IterLevels<'_, T> {
+    fn len(&self) -> usize {
+        self.depths.len()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterLevels<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let depth = self.depths.next_back()?;
+        Some(self.level(depth))
+    }
+}
+
+impl<T> FusedIterator for IterLevels<'_, T> {}
+
+#[derive(Debug)]
+pub struct IterLevelsMut<'a, T> {
+    arity: usize,
+    depths: Range<usize>,
+    tree_len: usize,
+    nodes: &'a mut [T],
+}
+
+impl<'a, T> IterLevelsMut<'a, T> {
+    pub(crate) fn new<R>(arity: usize, depths: R, tree: &'a mut [T]) -> Self
+    where
+        R: RangeBounds<usize>,
+    {
+        let tree_len = tree.len();
+        let depths = clamp_depths(arity, depths, tree_len);
+        let nodes = if depths.is_empty() {
+            &mut []
+        } else {
+            let start = *level(arity, depths.start, tree_len).start();
+            let end = *level(arity, depths.end - 1, tree_len).end();
+            &mut tree[start..=end]
+        };
+        Self {
+            arity,
+            depths,
+            tree_len,
+            nodes,
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterLevelsMut<'a, T> {
+    type Item = IterMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let depth = self.depths.next()?;
+        let level_len = level_len(self.arity, depth, self.tree_len);
+        let nodes = mem::take(&mut self.nodes);
+        let (level, rest) = nodes.split_at_mut(level_len);
+        self.nodes = rest;
+        Some(level.iter_mut())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.depths.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IterLevelsMut<'_, T> {
+    fn len(&self) -> usize {
+        self.depths.len()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterLevelsMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let depth = self.depths.next_back()?;
+        let level_len = level_len(self.arity, depth, self.tree_len);
+        let nodes = mem::take(&mut self.nodes);
+        let (rest, level) = nodes.split_at_mut(nodes.len() - level_len);
+        self.nodes = rest;
+        Some(level.iter_mut())
+    }
+}
+
+impl<T> FusedIterator for IterLevelsMut<'_, T> {}
+
+/// Only called with a depth that exists in a non-empty tree.
+fn level(arity: usize, depth: usize, tree_len: usize) -> RangeInclusive<usize> {
+    let Some(start) = level_start(arity, depth) else {
+        unreachable!()
+    };
+    let end = match depth
+        .checked_add(1)
+        .and_then(|depth| level_start(arity, depth))
+    {
+        Some(next) => next - 1,
+        None => usize::MAX,
+    };
+    start..=end.min(tree_len - 1)
+}
+
+fn level_len(arity: usize, depth: usize, tree_len: usize) -> usize {
+    let level = level(arity, depth, tree_len);
+    level.end() - level.start() + 1
+}
+
+fn clamp_depths<R>(arity: usize, depths: R, tree_len: usize) -> Range<usize>
+where
+    R: RangeBounds<usize>,
+{
+    if tree_len == 0 {
+        return 0..0;
+    }
+    let levels = split_flattened(arity, tree_len - 1).0 + 1;
+    let start = match depths.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match depths.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => levels,
+    };
+    let end = end.min(levels);
+    start.min(end)..end
+}
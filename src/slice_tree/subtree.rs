@@ -0,0 +1,78 @@
+use super::traverse::Nodes;
+use crate::index::{TreeIndex, next_level};
+use core::iter::FusedIterator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Subtree<'a, I, T> {
+    index: I,
+    tree: &'a [T],
+}
+
+impl<'a, I: TreeIndex, T> Subtree<'a, I, T> {
+    pub(crate) fn new(index: I, tree: &'a [T]) -> Self {
+        Self { index, tree }
+    }
+
+    pub fn index(&self) -> I {
+        self.index
+    }
+
+    pub fn root(&self) -> &'a T {
+        &self.tree[self.index.to_flattened()]
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter_levels().map(<[T]>::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn height(&self) -> usize {
+        self.iter_levels().count() - 1
+    }
+
+    pub fn level(&self, depth: usize) -> Option<&'a [T]> {
+        self.iter_levels().nth(depth)
+    }
+
+    pub fn iter_levels(&self) -> Levels<'a, T> {
+        Levels::new(self.index.arity(), self.index.to_flattened(), self.tree)
+    }
+
+    pub fn traverse_pre_order(&self) -> Nodes<'a, I::PreOrder, T> {
+        let indices = self.index.pre_order_from(self.tree.len());
+        Nodes::with_indices(indices, self.tree)
+    }
+
+    pub fn traverse_post_order(&self) -> Nodes<'a, I::PostOrder, T> {
+        let indices = self.index.post_order_from(self.tree.len());
+        Nodes::with_indices(indices, self.tree)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Levels<'a, T> {
+    arity: usize,
+    level: Option<(usize, usize)>,
+    tree: &'a [T],
+}
+
+impl<'a, T> Levels<'a, T> {
+    fn new(arity: usize, root: usize, tree: &'a [T]) -> Self {
+        let level = Some((root, root));
+        Self { arity, level, tree }
+    }
+}
+
+impl<'a, T> Iterator for Levels<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let level = next_level(self.arity, &mut self.level, self.tree.len())?;
+        self.tree.get(level)
+    }
+}
+
+impl<T> FusedIterator for Levels<'_, T> {}
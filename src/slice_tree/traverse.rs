@@ -1,7 +1,65 @@
-pub use self::in_order::{InOrder, InOrderMut};
-pub use self::post_order::{PostOrder, PostOrderMut};
-pub use self::pre_order::{PreOrder, PreOrderMut};
+pub use self::indexed::{Indexed, IndexedMut};
+pub use self::nodes::{Nodes, NodesMut};
 
-mod in_order;
-mod post_order;
-mod pre_order;
+use crate::index::traverse as indices;
+
+mod indexed;
+mod nodes;
+
+pub type PreOrder<'a, const N: usize, T> = Nodes<'a, indices::PreOrder<N>, T>;
+pub type PreOrderMut<'a, const N: usize, T> = NodesMut<'a, indices::PreOrder<N>, T>;
+pub type PostOrder<'a, const N: usize, T> = Nodes<'a, indices::PostOrder<N>, T>;
+pub type PostOrderMut<'a, const N: usize, T> = NodesMut<'a, indices::PostOrder<N>, T>;
+pub type InOrder<'a, T> = Nodes<'a, indices::InOrder, T>;
+pub type InOrderMut<'a, T> = NodesMut<'a, indices::InOrder, T>;
+
+pub type LevelOrderIndexed<'a, const N: usize, T> = Indexed<'a, indices::LevelOrder<N>, T>;
+pub type LevelOrderIndexedMut<'a, const N: usize, T> = IndexedMut<'a, indices::LevelOrder<N>, T>;
+pub type PreOrderIndexed<'a, const N: usize, T> = Indexed<'a, indices::PreOrder<N>, T>;
+pub type PreOrderIndexedMut<'a, const N: usize, T> = IndexedMut<'a, indices::PreOrder<N>, T>;
+pub type PostOrderIndexed<'a, const N: usize, T> = Indexed<'a, indices::PostOrder<N>, T>;
+pub type PostOrderIndexedMut<'a, const N: usize, T> = IndexedMut<'a, indices::PostOrder<N>, T>;
+pub type InOrderIndexed<'a, T> = Indexed<'a, indices::InOrder, T>;
+pub type InOrderIndexedMut<'a, T> = IndexedMut<'a, indices::InOrder, T>;
+
+impl<'a, const N: usize, T> PreOrder<'a, N, T> {
+    pub fn new(tree: &'a [T]) -> Self {
+        let indices = indices::PreOrder::new(tree.len());
+        Self::with_indices(indices, tree)
+    }
+}
+
+impl<'a, const N: usize, T> PreOrderMut<'a, N, T> {
+    pub fn new(tree: &'a mut [T]) -> Self {
+        let indices = indices::PreOrder::new(tree.len());
+        Self::with_indices(indices, tree)
+    }
+}
+
+impl<'a, const N: usize, T> PostOrder<'a, N, T> {
+    pub fn new(tree: &'a [T]) -> Self {
+        let indices = indices::PostOrder::new(tree.len());
+        Self::with_indices(indices, tree)
+    }
+}
+
+impl<'a, const N: usize, T> PostOrderMut<'a, N, T> {
+    pub fn new(tree: &'a mut [T]) -> Self {
+        let indices = indices::PostOrder::new(tree.len());
+        Self::with_indices(indices, tree)
+    }
+}
+
+impl<'a, T> InOrder<'a, T> {
+    pub fn new(tree: &'a [T]) -> Self {
+        let indices = indices::InOrder::new(tree.len());
+        Self::with_indices(indices, tree)
+    }
+}
+
+impl<'a, T> InOrderMut<'a, T> {
+    pub fn new(tree: &'a mut [T]) -> Self {
+        let indices = indices::InOrder::new(tree.len());
+        Self::with_indices(indices, tree)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::index::traverse::{MaxDepth, Traversal};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct Indexed<'a, I, T> {
+    indices: I,
+    base: *const T,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, I, T> Indexed<'a, I, T> {
+    pub(crate) fn new(indices: I, tree: &'a [T]) -> Self {
+        let base = tree.as_ptr();
+        let marker = PhantomData;
+        Self {
+            indices,
+            base,
+            marker,
+        }
+    }
+}
+
+impl<I: MaxDepth, T> Indexed<'_, I, T> {
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.indices = self.indices.max_depth(depth);
+        self
+    }
+}
+
+impl<'a, I: Traversal, T> Iterator for Indexed<'a, I, T> {
+    type Item = (I::Item, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_flattened()?;
+        let node = unsafe { &*self.base.add(index) };
+        Some((self.indices.index(index), node))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<I: Traversal, T> ExactSizeIterator for Indexed<'_, I, T> {
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+impl<I: Traversal, T> DoubleEndedIterator for Indexed<'_, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_back_flattened()?;
+        let node = unsafe { &*self.base.add(index) };
+        Some((self.indices.index(index), node))
+    }
+}
+
+impl<I: Traversal, T> FusedIterator for Indexed<'_, I, T> {}
+
+#[derive(Debug)]
+pub struct IndexedMut<'a, I, T> {
+    indices: I,
+    base: *mut T,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, I, T> IndexedMut<'a, I, T> {
+    pub(crate) fn new(indices: I, tree: &'a mut [T]) -> Self {
+        let base = tree.as_mut_ptr();
+        let marker = PhantomData;
+        Self {
+            indices,
+            base,
+            marker,
+        }
+    }
+}
+
+impl<I: MaxDepth, T> IndexedMut<'_, I, T> {
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.indices = self.indices.max_depth(depth);
+        self
+    }
+}
+
+impl<'a, I: Traversal, T> Iterator for IndexedMut<'a, I, T> {
+    type Item = (I::Item, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_flattened()?;
+        let node = unsafe { &mut *self.base.add(index) };
+        Some((self.indices.index(index), node))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<I: Traversal, T> ExactSizeIterator for IndexedMut<'_, I, T> {
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+impl<I: Traversal, T> DoubleEndedIterator for IndexedMut<'_, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_back_flattened()?;
+        let node = unsafe { &mut *self.base.add(index) };
+        Some((self.indices.index(index), node))
+    }
+}
+
+impl<I: Traversal, T> FusedIterator for IndexedMut<'_, I, T> {}
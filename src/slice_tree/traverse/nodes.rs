@@ -0,0 +1,115 @@
+use crate::index::traverse::{MaxDepth, Traversal};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct Nodes<'a, I, T> {
+    indices: I,
+    base: *const T,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, I, T> Nodes<'a, I, T> {
+    pub(crate) fn with_indices(indices: I, tree: &'a [T]) -> Self {
+        let base = tree.as_ptr();
+        let marker = PhantomData;
+        Self {
+            indices,
+            base,
+            marker,
+        }
+    }
+}
+
+impl<I: MaxDepth, T> Nodes<'_, I, T> {
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.indices = self.indices.max_depth(depth);
+        self
+    }
+}
+
+impl<'a, I: Traversal, T> Iterator for Nodes<'a, I, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_flattened()?;
+        let node = unsafe { &*self.base.add(index) };
+        Some(node)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<I: Traversal, T> ExactSizeIterator for Nodes<'_, I, T> {
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+impl<I: Traversal, T> DoubleEndedIterator for Nodes<'_, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_back_flattened()?;
+        let node = unsafe { &*self.base.add(index) };
+        Some(node)
+    }
+}
+
+impl<I: Traversal, T> FusedIterator for Nodes<'_, I, T> {}
+
+#[derive(Debug)]
+pub struct NodesMut<'a, I, T> {
+    indices: I,
+    base: *mut T,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, I, T> NodesMut<'a, I, T> {
+    pub(crate) fn with_indices(indices: I, tree: &'a mut [T]) -> Self {
+        let base = tree.as_mut_ptr();
+        let marker = PhantomData;
+        Self {
+            indices,
+            base,
+            marker,
+        }
+    }
+}
+
+impl<I: MaxDepth, T> NodesMut<'_, I, T> {
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.indices = self.indices.max_depth(depth);
+        self
+    }
+}
+
+impl<'a, I: Traversal, T> Iterator for NodesMut<'a, I, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_flattened()?;
+        let node = unsafe { &mut *self.base.add(index) };
+        Some(node)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<I: Traversal, T> ExactSizeIterator for NodesMut<'_, I, T> {
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+impl<I: Traversal, T> DoubleEndedIterator for NodesMut<'_, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_back_flattened()?;
+        let node = unsafe { &mut *self.base.add(index) };
+        Some(node)
+    }
+}
+
+impl<I: Traversal, T> FusedIterator for NodesMut<'_, I, T> {}
@@ -0,0 +1,531 @@
+use crate::slice_tree::traverse::{
+    InOrder, InOrderIndexed, InOrderIndexedMut, InOrderMut, LevelOrderIndexed,
+    LevelOrderIndexedMut, PostOrder, PostOrderIndexed, PostOrderIndexedMut, PostOrderMut, PreOrder,
+    PreOrderIndexed, PreOrderIndexedMut, PreOrderMut,
+};
+use crate::slice_tree::{Ancestors, IterLevels, IterLevelsMut, Subtree};
+use crate::{CompleteBinaryTree, CompleteTree, Index, IndexRange, SliceTree};
+use alloc::vec::{self, Vec};
+use core::ops::{Deref, DerefMut, RangeBounds};
+use core::slice::{Iter, IterMut};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VecTree<const N: usize, T>(Vec<T>);
+
+impl<const N: usize, T> VecTree<N, T> {
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+
+    pub fn reserve_level(&mut self) {
+        let depth = Index::<N>::from_flattened(self.0.len()).depth();
+        let level = IndexRange::<N>::level(depth).to_flattened();
+        let additional = level.end() - self.0.len() + 1;
+        self.0.reserve(additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
+    pub fn push(&mut self, node: T) {
+        self.0.push(node);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    pub fn as_slice_tree(&self) -> &SliceTree<N, T> {
+        self.0.as_slice().into()
+    }
+
+    pub fn as_mut_slice_tree(&mut self) -> &mut SliceTree<N, T> {
+        self.0.as_mut_slice().into()
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> VecTree<2, T> {
+    pub fn from_sorted(mut nodes: Vec<T>) -> Self {
+        SliceTree::from_sorted(&mut nodes);
+        Self(nodes)
+    }
+
+    pub fn into_sorted(mut self) -> Vec<T> {
+        self.as_mut_slice_tree().to_sorted();
+        self.0
+    }
+}
+
+impl<const N: usize, T: Clone> VecTree<N, T> {
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.0.extend_from_slice(other);
+    }
+}
+
+impl<const N: usize, T> Default for VecTree<N, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T> From<Vec<T>> for VecTree<N, T> {
+    fn from(value: Vec<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const N: usize, T> From<VecTree<N, T>> for Vec<T> {
+    fn from(value: VecTree<N, T>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize, T> Deref for VecTree<N, T> {
+    type Target = SliceTree<N, T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice_tree()
+    }
+}
+
+impl<const N: usize, T> DerefMut for VecTree<N, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice_tree()
+    }
+}
+
+impl<const N: usize, T> AsRef<[T]> for VecTree<N, T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<const N: usize, T> AsMut<[T]> for VecTree<N, T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize, T> AsRef<SliceTree<N, T>> for VecTree<N, T> {
+    fn as_ref(&self) -> &SliceTree<N, T> {
+        self.as_slice_tree()
+    }
+}
+
+impl<const N: usize, T> AsMut<SliceTree<N, T>> for VecTree<N, T> {
+    fn as_mut(&mut self) -> &mut SliceTree<N, T> {
+        self.as_mut_slice_tree()
+    }
+}
+
+impl<const N: usize, T> Extend<T> for VecTree<N, T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a, const N: usize, T: Copy + 'a> Extend<&'a T> for VecTree<N, T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<const N: usize, T> FromIterator<T> for VecTree<N, T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(Vec::from_iter(iter))
+    }
+}
+
+impl<const N: usize, T> IntoIterator for VecTree<N, T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, const N: usize, T> IntoIterator for &'a VecTree<N, T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, const N: usize, T> IntoIterator for &'a mut VecTree<N, T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<const N: usize, T> CompleteTree<N> for VecTree<N, T> {
+    type Node = T;
+
+    type IterChildren<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterChildrenMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevel<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevelMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type LevelOrder<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type LevelOrderMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type PreOrder<'a>
+        = PreOrder<'a, N, T>
+    where
+        Self: 'a;
+
+    type PreOrderMut<'a>
+        = PreOrderMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrder<'a>
+        = PostOrder<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrderMut<'a>
+        = PostOrderMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexed<'a>
+        = LevelOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type LevelOrderIndexedMut<'a>
+        = LevelOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type PreOrderIndexed<'a>
+        = PreOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type PreOrderIndexedMut<'a>
+        = PreOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrderIndexed<'a>
+        = PostOrderIndexed<'a, N, T>
+    where
+        Self: 'a;
+
+    type PostOrderIndexedMut<'a>
+        = PostOrderIndexedMut<'a, N, T>
+    where
+        Self: 'a;
+
+    type Ancestors<'a>
+        = Ancestors<'a, N, T>
+    where
+        Self: 'a;
+
+    type IterSiblings<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterSiblingsMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type Subtree<'a>
+        = Subtree<'a, Index<N>, T>
+    where
+        Self: 'a;
+
+    type IterLeaves<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterLeavesMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterInternal<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a;
+
+    type IterInternalMut<'a>
+        = IterMut<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevels<'a>
+        = IterLevels<'a, T>
+    where
+        Self: 'a;
+
+    type IterLevelsMut<'a>
+        = IterLevelsMut<'a, T>
+    where
+        Self: 'a;
+
+    fn len(&self) -> usize {
+        CompleteTree::<N>::len(self.as_slice_tree())
+    }
+
+    fn swap(&mut self, index_a: Index<N>, index_b: Index<N>) -> Option<()> {
+        CompleteTree::<N>::swap(self.as_mut_slice_tree(), index_a, index_b)
+    }
+
+    fn replace(&mut self, index: Index<N>, node: Self::Node) -> Option<Self::Node> {
+        CompleteTree::<N>::replace(self.as_mut_slice_tree(), index, node)
+    }
+
+    fn node(&self, index: Index<N>) -> Option<&Self::Node> {
+        CompleteTree::<N>::node(self.as_slice_tree(), index)
+    }
+
+    fn node_mut(&mut self, index: Index<N>) -> Option<&mut Self::Node> {
+        CompleteTree::<N>::node_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn root(&self) -> Option<&Self::Node> {
+        CompleteTree::<N>::root(self.as_slice_tree())
+    }
+
+    fn root_mut(&mut self) -> Option<&mut Self::Node> {
+        CompleteTree::<N>::root_mut(self.as_mut_slice_tree())
+    }
+
+    fn last(&self) -> Option<&Self::Node> {
+        CompleteTree::<N>::last(self.as_slice_tree())
+    }
+
+    fn last_mut(&mut self) -> Option<&mut Self::Node> {
+        CompleteTree::<N>::last_mut(self.as_mut_slice_tree())
+    }
+
+    fn ancestors(&self, index: Index<N>) -> Option<Self::Ancestors<'_>> {
+        CompleteTree::<N>::ancestors(self.as_slice_tree(), index)
+    }
+
+    fn iter_siblings(&self, index: Index<N>) -> Option<Self::IterSiblings<'_>> {
+        CompleteTree::<N>::iter_siblings(self.as_slice_tree(), index)
+    }
+
+    fn iter_siblings_mut(&mut self, index: Index<N>) -> Option<Self::IterSiblingsMut<'_>> {
+        CompleteTree::<N>::iter_siblings_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn iter_children(&self, index: Index<N>) -> Option<Self::IterChildren<'_>> {
+        CompleteTree::<N>::iter_children(self.as_slice_tree(), index)
+    }
+
+    fn iter_children_mut(&mut self, index: Index<N>) -> Option<Self::IterChildrenMut<'_>> {
+        CompleteTree::<N>::iter_children_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn subtree(&self, index: Index<N>) -> Option<Self::Subtree<'_>> {
+        CompleteTree::<N>::subtree(self.as_slice_tree(), index)
+    }
+
+    fn iter_level(&self, depth: usize) -> Option<Self::IterLevel<'_>> {
+        CompleteTree::<N>::iter_level(self.as_slice_tree(), depth)
+    }
+
+    fn iter_level_mut(&mut self, depth: usize) -> Option<Self::IterLevelMut<'_>> {
+        CompleteTree::<N>::iter_level_mut(self.as_mut_slice_tree(), depth)
+    }
+
+    fn iter_leaves(&self) -> Self::IterLeaves<'_> {
+        CompleteTree::<N>::iter_leaves(self.as_slice_tree())
+    }
+
+    fn iter_leaves_mut(&mut self) -> Self::IterLeavesMut<'_> {
+        CompleteTree::<N>::iter_leaves_mut(self.as_mut_slice_tree())
+    }
+
+    fn iter_internal(&self) -> Self::IterInternal<'_> {
+        CompleteTree::<N>::iter_internal(self.as_slice_tree())
+    }
+
+    fn iter_internal_mut(&mut self) -> Self::IterInternalMut<'_> {
+        CompleteTree::<N>::iter_internal_mut(self.as_mut_slice_tree())
+    }
+
+    fn iter_levels<R>(&self, depths: R) -> Self::IterLevels<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        CompleteTree::<N>::iter_levels(self.as_slice_tree(), depths)
+    }
+
+    fn iter_levels_mut<R>(&mut self, depths: R) -> Self::IterLevelsMut<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        CompleteTree::<N>::iter_levels_mut(self.as_mut_slice_tree(), depths)
+    }
+
+    fn traverse_level_order(&self) -> Self::LevelOrder<'_> {
+        CompleteTree::<N>::traverse_level_order(self.as_slice_tree())
+    }
+
+    fn traverse_level_order_mut(&mut self) -> Self::LevelOrderMut<'_> {
+        CompleteTree::<N>::traverse_level_order_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_pre_order(&self) -> Self::PreOrder<'_> {
+        CompleteTree::<N>::traverse_pre_order(self.as_slice_tree())
+    }
+
+    fn traverse_pre_order_mut(&mut self) -> Self::PreOrderMut<'_> {
+        CompleteTree::<N>::traverse_pre_order_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_post_order(&self) -> Self::PostOrder<'_> {
+        CompleteTree::<N>::traverse_post_order(self.as_slice_tree())
+    }
+
+    fn traverse_post_order_mut(&mut self) -> Self::PostOrderMut<'_> {
+        CompleteTree::<N>::traverse_post_order_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_pre_order_from(&self, index: Index<N>) -> Option<Self::PreOrder<'_>> {
+        CompleteTree::<N>::traverse_pre_order_from(self.as_slice_tree(), index)
+    }
+
+    fn traverse_pre_order_from_mut(&mut self, index: Index<N>) -> Option<Self::PreOrderMut<'_>> {
+        CompleteTree::<N>::traverse_pre_order_from_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn traverse_post_order_from(&self, index: Index<N>) -> Option<Self::PostOrder<'_>> {
+        CompleteTree::<N>::traverse_post_order_from(self.as_slice_tree(), index)
+    }
+
+    fn traverse_post_order_from_mut(&mut self, index: Index<N>) -> Option<Self::PostOrderMut<'_>> {
+        CompleteTree::<N>::traverse_post_order_from_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn traverse_level_order_indexed(&self) -> Self::LevelOrderIndexed<'_> {
+        CompleteTree::<N>::traverse_level_order_indexed(self.as_slice_tree())
+    }
+
+    fn traverse_level_order_indexed_mut(&mut self) -> Self::LevelOrderIndexedMut<'_> {
+        CompleteTree::<N>::traverse_level_order_indexed_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_pre_order_indexed(&self) -> Self::PreOrderIndexed<'_> {
+        CompleteTree::<N>::traverse_pre_order_indexed(self.as_slice_tree())
+    }
+
+    fn traverse_pre_order_indexed_mut(&mut self) -> Self::PreOrderIndexedMut<'_> {
+        CompleteTree::<N>::traverse_pre_order_indexed_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_post_order_indexed(&self) -> Self::PostOrderIndexed<'_> {
+        CompleteTree::<N>::traverse_post_order_indexed(self.as_slice_tree())
+    }
+
+    fn traverse_post_order_indexed_mut(&mut self) -> Self::PostOrderIndexedMut<'_> {
+        CompleteTree::<N>::traverse_post_order_indexed_mut(self.as_mut_slice_tree())
+    }
+}
+
+impl<T> CompleteBinaryTree for VecTree<2, T> {
+    type InOrder<'a>
+        = InOrder<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderMut<'a>
+        = InOrderMut<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderIndexed<'a>
+        = InOrderIndexed<'a, T>
+    where
+        Self: 'a;
+
+    type InOrderIndexedMut<'a>
+        = InOrderIndexedMut<'a, T>
+    where
+        Self: 'a;
+
+    fn traverse_in_order(&self) -> InOrder<'_, T> {
+        CompleteBinaryTree::traverse_in_order(self.as_slice_tree())
+    }
+
+    fn traverse_in_order_mut(&mut self) -> InOrderMut<'_, T> {
+        CompleteBinaryTree::traverse_in_order_mut(self.as_mut_slice_tree())
+    }
+
+    fn traverse_in_order_from(&self, index: Index<2>) -> Option<Self::InOrder<'_>> {
+        CompleteBinaryTree::traverse_in_order_from(self.as_slice_tree(), index)
+    }
+
+    fn traverse_in_order_from_mut(&mut self, index: Index<2>) -> Option<Self::InOrderMut<'_>> {
+        CompleteBinaryTree::traverse_in_order_from_mut(self.as_mut_slice_tree(), index)
+    }
+
+    fn traverse_in_order_indexed(&self) -> Self::InOrderIndexed<'_> {
+        CompleteBinaryTree::traverse_in_order_indexed(self.as_slice_tree())
+    }
+
+    fn traverse_in_order_indexed_mut(&mut self) -> Self::InOrderIndexedMut<'_> {
+        CompleteBinaryTree::traverse_in_order_indexed_mut(self.as_mut_slice_tree())
+    }
+}
@@ -0,0 +1,30 @@
+use bypond::display::Layout;
+use bypond::{CompleteTree, SliceTree};
+
+#[test]
+fn narrow_width_falls_back_to_sideways() {
+    let nodes: Vec<u32> = (0..13).collect();
+    let tree: &SliceTree<3, u32> = nodes.as_slice().into();
+
+    let clipped = tree.display().max_width(10).to_string();
+    let sideways = tree.display().layout(Layout::Sideways).to_string();
+    assert_eq!(clipped.lines().next(), Some("0"));
+    assert!(clipped.lines().all(|line| line.chars().count() <= 10));
+    let sideways: Vec<_> = sideways
+        .lines()
+        .map(|line| line.chars().take(10).collect::<String>())
+        .collect();
+    assert_eq!(clipped.lines().collect::<Vec<_>>(), sideways);
+}
+
+#[test]
+fn wide_enough_width_keeps_top_down() {
+    let nodes: Vec<u32> = (0..4).collect();
+    let tree: &SliceTree<3, u32> = nodes.as_slice().into();
+
+    let unclipped = tree.display().to_string();
+    let widest = unclipped.lines().map(|line| line.chars().count()).max();
+    let clipped = tree.display().max_width(widest.unwrap()).to_string();
+    assert_eq!(clipped, unclipped);
+    assert_eq!(unclipped.lines().count(), 3);
+}
@@ -0,0 +1,110 @@
+use bypond::SliceTree;
+use std::cmp::Ordering;
+
+// Ordered by `key` alone, so a search that lands on the wrong duplicate shows
+// up as a wrong `rank`.
+#[derive(Debug, Clone, Copy)]
+struct Keyed {
+    key: u32,
+    rank: usize,
+}
+
+impl PartialEq for Keyed {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Keyed {}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Keyed {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+fn sorted(len: usize, spread: u32) -> Vec<Keyed> {
+    (0..len)
+        .map(|rank| Keyed {
+            key: rank as u32 / spread * 2,
+            rank,
+        })
+        .collect()
+}
+
+fn probe(key: u32) -> Keyed {
+    Keyed {
+        key,
+        rank: usize::MAX,
+    }
+}
+
+#[test]
+fn searches_match_partition_point() {
+    for len in 0..70 {
+        // A spread of 1 gives distinct keys, larger spreads give runs of
+        // duplicates. Keys are even, so odd probes are always absent.
+        for spread in [1, 2, 3, 7] {
+            let expected = sorted(len, spread);
+            let mut nodes = expected.clone();
+            let tree = SliceTree::from_sorted(&mut nodes);
+
+            let mut buffer = vec![probe(0); len];
+            let cloned = SliceTree::from_sorted_into(&expected, &mut buffer).unwrap();
+            assert_eq!(
+                cloned
+                    .as_ref()
+                    .iter()
+                    .map(|node| node.rank)
+                    .collect::<Vec<_>>(),
+                tree.as_ref()
+                    .iter()
+                    .map(|node| node.rank)
+                    .collect::<Vec<_>>(),
+            );
+
+            let max = expected.last().map_or(0, |node| node.key);
+            for key in 0..=max + 2 {
+                let probe = probe(key);
+                let lower = expected.partition_point(|node| *node < probe);
+                let upper = expected.partition_point(|node| *node <= probe);
+                let context = format!("len = {len}, spread = {spread}, key = {key}");
+
+                assert_eq!(
+                    tree.lower_bound(&probe).map(|node| node.rank),
+                    expected.get(lower).map(|node| node.rank),
+                    "lower_bound, {context}",
+                );
+                assert_eq!(
+                    tree.upper_bound(&probe).map(|node| node.rank),
+                    expected.get(upper).map(|node| node.rank),
+                    "upper_bound, {context}",
+                );
+                assert_eq!(tree.contains(&probe), lower != upper, "contains, {context}");
+            }
+
+            let mut buffer = vec![probe(0); len];
+            tree.to_sorted_into(&mut buffer).unwrap();
+            assert!(buffer.iter().map(|node| node.rank).eq(0..len));
+            let round_trip = tree.to_sorted();
+            assert!(round_trip.iter().map(|node| node.rank).eq(0..len));
+        }
+    }
+}
+
+#[test]
+fn mismatched_buffers_are_rejected() {
+    let sorted = [1, 2, 3];
+    let mut buffer = [0; 2];
+    assert!(SliceTree::from_sorted_into(&sorted, &mut buffer).is_none());
+
+    let mut nodes = sorted;
+    let tree = SliceTree::from_sorted(&mut nodes);
+    assert!(tree.to_sorted_into(&mut buffer).is_none());
+}
@@ -0,0 +1,164 @@
+use bypond::heap::{self, DaryHeap, IndexedHeap, MaxComparator, MinComparator};
+use bypond::{CompleteTree, Index, SliceTree};
+
+fn values(len: usize, seed: u64) -> Vec<u32> {
+    // A small LCG keeps the inputs deterministic and full of duplicates.
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as u32 % 50
+        })
+        .collect()
+}
+
+fn check_heap<const N: usize>() {
+    for len in 0..70 {
+        let input = values(len, len as u64);
+        let mut sorted = input.clone();
+        sorted.sort();
+
+        let mut max_heap = DaryHeap::<N, u32>::new();
+        let mut min_heap = DaryHeap::<N, u32, MinComparator>::new();
+        for &value in &input {
+            max_heap.push(value);
+            min_heap.push(value);
+            assert!(heap::is_heap(max_heap.as_slice_tree(), &MaxComparator));
+            assert!(heap::is_heap(min_heap.as_slice_tree(), &MinComparator));
+        }
+        assert_eq!(max_heap.peek(), sorted.last());
+        assert_eq!(min_heap.peek(), sorted.first());
+
+        let popped: Vec<_> = std::iter::from_fn(|| max_heap.pop()).collect();
+        assert_eq!(popped, sorted.iter().rev().copied().collect::<Vec<_>>());
+        let popped: Vec<_> = std::iter::from_fn(|| min_heap.pop()).collect();
+        assert_eq!(popped, sorted);
+
+        let max_heap: DaryHeap<N, u32> = input.iter().copied().collect();
+        assert_eq!(max_heap.into_sorted_vec(), sorted);
+        let min_heap: DaryHeap<N, u32, MinComparator> = input.iter().copied().collect();
+        let mut descending = sorted.clone();
+        descending.reverse();
+        assert_eq!(min_heap.into_sorted_vec(), descending);
+    }
+}
+
+#[test]
+fn pop_order_and_sorted_vec() {
+    check_heap::<1>();
+    check_heap::<2>();
+    check_heap::<3>();
+    check_heap::<4>();
+}
+
+#[test]
+fn heapify_and_sift() {
+    for len in 0..70 {
+        let mut nodes = values(len, 7 + len as u64);
+        let tree: &mut SliceTree<3, u32> = nodes.as_mut_slice().into();
+        heap::heapify(tree, &MaxComparator);
+        assert!(heap::is_heap(tree, &MaxComparator));
+
+        if let Some(last) = CompleteTree::<3>::last_mut(tree) {
+            *last = 100;
+            let last = Index::from_flattened(len - 1);
+            let root = heap::sift_up(tree, last, &MaxComparator);
+            assert_eq!(root.map(|index| index.to_flattened()), Some(0));
+            assert!(heap::is_heap(tree, &MaxComparator));
+
+            *CompleteTree::<3>::root_mut(tree).unwrap() = 0;
+            heap::sift_down(tree, Index::root(), &MaxComparator);
+            assert!(heap::is_heap(tree, &MaxComparator));
+        }
+    }
+}
+
+fn check_dijkstra<const N: usize>() {
+    // Keys are spread over the whole `usize` range, which a dense position
+    // table could not hold.
+    const STRIDE: usize = usize::MAX / 64;
+
+    for len in 1..40 {
+        let weights = values(len * 3, len as u64);
+        let edges: Vec<(usize, usize, u64)> = (0..len * 3)
+            .map(|edge| (edge / 3, (edge * 7 + 1) % len, u64::from(weights[edge])))
+            .collect();
+
+        let mut expected = vec![u64::MAX; len];
+        expected[0] = 0;
+        for _ in 0..len {
+            for &(from, to, weight) in &edges {
+                if expected[from] != u64::MAX {
+                    expected[to] = expected[to].min(expected[from] + weight);
+                }
+            }
+        }
+
+        let mut distances = vec![u64::MAX; len];
+        let mut queue = IndexedHeap::<N, u64, MinComparator>::new();
+        queue.push(0, 0);
+        while let Some((key, distance)) = queue.pop() {
+            let from = key / STRIDE;
+            distances[from] = distance;
+            for &(_, to, weight) in edges.iter().filter(|edge| edge.0 == from) {
+                let candidate = distance + weight;
+                if distances[to] != u64::MAX {
+                    continue;
+                }
+                match queue.get(to * STRIDE) {
+                    None => assert_eq!(queue.push(to * STRIDE, candidate), None),
+                    Some(&current) if candidate < current => {
+                        let old = queue.increase_priority(to * STRIDE, candidate);
+                        assert_eq!(old, Some(current));
+                    }
+                    Some(_) => {}
+                }
+                assert!(heap::is_heap(
+                    queue.as_slice_tree(),
+                    &|a: &(usize, u64), b: &(usize, u64)| b.1.cmp(&a.1)
+                ));
+            }
+        }
+        assert_eq!(distances, expected, "N = {N}, len = {len}");
+    }
+}
+
+#[test]
+fn indexed_heap_runs_dijkstra() {
+    check_dijkstra::<1>();
+    check_dijkstra::<2>();
+    check_dijkstra::<3>();
+    check_dijkstra::<4>();
+}
+
+#[test]
+fn indexed_heap_tracks_positions() {
+    let mut queue = IndexedHeap::<3, u32>::new();
+    for (key, priority) in values(40, 7).into_iter().enumerate() {
+        assert_eq!(queue.push(key * 1000, priority), None);
+    }
+    assert_eq!(queue.decrease_priority(0, 0), Some(values(1, 7)[0]));
+    assert_eq!(queue.increase_priority(39_000, 99), Some(values(40, 7)[39]));
+    assert_eq!(queue.change_priority(5_000, 60), Some(values(40, 7)[5]));
+    assert_eq!(queue.remove(12_000), Some(values(40, 7)[12]));
+    assert_eq!(queue.increase_priority(12_000, 1), None);
+    assert_eq!(queue.push(1_000, 98), Some(values(40, 7)[1]));
+
+    for key in (0..40).map(|key| key * 1000) {
+        match queue.position(key) {
+            None => assert_eq!(key, 12_000),
+            Some(index) => assert_eq!(queue.as_slice_tree()[index].0, key),
+        }
+    }
+    assert_eq!(queue.pop(), Some((39_000, 99)));
+    assert_eq!(queue.pop(), Some((1_000, 98)));
+    assert_eq!(queue.pop(), Some((5_000, 60)));
+    let mut previous = u32::MAX;
+    while let Some((key, priority)) = queue.pop() {
+        assert!(priority <= previous);
+        assert!(!queue.contains(key));
+        previous = priority;
+    }
+}
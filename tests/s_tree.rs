@@ -0,0 +1,92 @@
+use bypond::s_tree::STree;
+use std::cmp::Ordering;
+
+// Ordered by `key` alone, so a search that lands on the wrong duplicate or on
+// block padding shows up as a wrong `rank`.
+#[derive(Debug, Clone, Copy)]
+struct Keyed {
+    key: u32,
+    rank: usize,
+}
+
+impl PartialEq for Keyed {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Keyed {}
+
+impl PartialOrd for Keyed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Keyed {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+fn sorted(len: usize, spread: u32) -> Vec<Keyed> {
+    (0..len)
+        .map(|rank| Keyed {
+            key: rank as u32 / spread * 2,
+            rank,
+        })
+        .collect()
+}
+
+fn probe(key: u32) -> Keyed {
+    Keyed {
+        key,
+        rank: usize::MAX,
+    }
+}
+
+fn check_searches<const N: usize>() {
+    for len in 0..90 {
+        // A spread of 1 gives distinct keys, larger spreads give runs of
+        // duplicates. Keys are even, so odd probes are always absent.
+        for spread in [1, 2, 3, 7] {
+            let expected = sorted(len, spread);
+            let tree = STree::<N, _>::from_sorted_slice(&expected);
+            assert_eq!(tree.len(), len);
+            assert_eq!(tree.is_empty(), len == 0);
+            assert_eq!(tree.block_count(), len.div_ceil(N - 1));
+
+            let max = expected.last().map_or(0, |node| node.key);
+            for key in 0..=max + 2 {
+                let probe = probe(key);
+                let lower = expected.partition_point(|node| *node < probe);
+                let upper = expected.partition_point(|node| *node <= probe);
+                let context = format!("N = {N}, len = {len}, spread = {spread}, key = {key}");
+
+                assert_eq!(
+                    tree.lower_bound(&probe).map(|node| node.rank),
+                    expected.get(lower).map(|node| node.rank),
+                    "lower_bound, {context}",
+                );
+                assert_eq!(
+                    tree.upper_bound(&probe).map(|node| node.rank),
+                    expected.get(upper).map(|node| node.rank),
+                    "upper_bound, {context}",
+                );
+                assert_eq!(tree.contains(&probe), lower != upper, "contains, {context}");
+            }
+
+            let round_trip = tree.into_sorted();
+            assert!(round_trip.iter().map(|node| node.rank).eq(0..len));
+        }
+    }
+}
+
+#[test]
+fn searches_match_partition_point() {
+    check_searches::<2>();
+    check_searches::<3>();
+    check_searches::<4>();
+    check_searches::<5>();
+    check_searches::<9>();
+}
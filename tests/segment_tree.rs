@@ -0,0 +1,221 @@
+use bypond::segment_tree::{Action, LazySegmentTree, Monoid, SegmentTree};
+
+fn values(len: usize, seed: u64) -> Vec<u64> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) % 20
+        })
+        .collect()
+}
+
+struct Sum;
+
+impl Monoid<u64> for Sum {
+    fn identity(&self) -> u64 {
+        0
+    }
+
+    fn combine(&self, a: &u64, b: &u64) -> u64 {
+        a + b
+    }
+}
+
+/// Folds digits left to right, so any reordering of operands shows up.
+struct Digits;
+
+impl Monoid<(u64, u32)> for Digits {
+    fn identity(&self) -> (u64, u32) {
+        (0, 0)
+    }
+
+    fn combine(&self, a: &(u64, u32), b: &(u64, u32)) -> (u64, u32) {
+        let shift = 31u64.wrapping_pow(b.1);
+        (a.0.wrapping_mul(shift).wrapping_add(b.0), a.1 + b.1)
+    }
+}
+
+fn fold<T, M: Monoid<T>>(monoid: &M, leaves: &[T]) -> T {
+    leaves
+        .iter()
+        .fold(monoid.identity(), |acc, leaf| monoid.combine(&acc, leaf))
+}
+
+#[test]
+fn query_matches_fold() {
+    for len in 0..40 {
+        let mut leaves = values(len, len as u64);
+        let mut tree = SegmentTree::build(leaves.iter().copied(), Sum);
+        let mut digits: Vec<_> = leaves.iter().map(|&leaf| (leaf, 1)).collect();
+        let mut ordered = SegmentTree::build(digits.iter().copied(), Digits);
+
+        for round in 0..3 {
+            for start in 0..=len {
+                for end in start..=len {
+                    assert_eq!(
+                        tree.query(start..end),
+                        Some(fold(&Sum, &leaves[start..end]))
+                    );
+                    assert_eq!(
+                        ordered.query(start..end),
+                        Some(fold(&Digits, &digits[start..end]))
+                    );
+                }
+            }
+            assert_eq!(tree.query(..), Some(fold(&Sum, &leaves)));
+            assert_eq!(tree.query(0..len + 1), None);
+
+            for (index, leaf) in values(len, round).into_iter().enumerate().step_by(3) {
+                assert_eq!(tree.update(index, leaf), Some(leaves[index]));
+                ordered.update(index, (leaf, 1));
+                leaves[index] = leaf;
+                digits[index] = (leaf, 1);
+            }
+        }
+        assert_eq!(tree.update(len, 0), None);
+    }
+}
+
+#[test]
+fn binary_search_matches_brute_force() {
+    for len in 0..40 {
+        let leaves = values(len, 3 + len as u64);
+        let tree = SegmentTree::build(leaves.iter().copied(), Sum);
+        for limit in [0, 5, 17, 40, 1000] {
+            let fits = |sum: &u64| *sum <= limit;
+            for start in 0..=len {
+                let expected = (start..=len)
+                    .rev()
+                    .find(|&end| fold(&Sum, &leaves[start..end]) <= limit);
+                assert_eq!(tree.max_right(start, fits), expected);
+            }
+            for end in 0..=len {
+                let expected = (0..=end).find(|&start| fold(&Sum, &leaves[start..end]) <= limit);
+                assert_eq!(tree.min_left(end, fits), expected);
+            }
+            assert_eq!(tree.max_right(len + 1, fits), None);
+            assert_eq!(tree.min_left(len + 1, fits), None);
+        }
+    }
+}
+
+/// Sums paired with segment lengths, so that range addition can scale the tag.
+struct SumLen;
+
+impl Monoid<(u64, u64)> for SumLen {
+    fn identity(&self) -> (u64, u64) {
+        (0, 0)
+    }
+
+    fn combine(&self, a: &(u64, u64), b: &(u64, u64)) -> (u64, u64) {
+        (a.0 + b.0, a.1 + b.1)
+    }
+}
+
+struct Adding;
+
+impl Action<(u64, u64)> for Adding {
+    type Tag = u64;
+
+    fn identity(&self) -> u64 {
+        0
+    }
+
+    fn apply(&self, tag: &u64, node: &(u64, u64)) -> (u64, u64) {
+        (node.0 + tag * node.1, node.1)
+    }
+
+    fn compose(&self, outer: &u64, inner: &u64) -> u64 {
+        outer + inner
+    }
+}
+
+struct Min;
+
+impl Monoid<u64> for Min {
+    fn identity(&self) -> u64 {
+        u64::MAX
+    }
+
+    fn combine(&self, a: &u64, b: &u64) -> u64 {
+        *a.min(b)
+    }
+}
+
+struct Assigning;
+
+impl Action<u64> for Assigning {
+    type Tag = Option<u64>;
+
+    fn identity(&self) -> Option<u64> {
+        None
+    }
+
+    fn apply(&self, tag: &Option<u64>, node: &u64) -> u64 {
+        tag.unwrap_or(*node)
+    }
+
+    fn compose(&self, outer: &Option<u64>, inner: &Option<u64>) -> Option<u64> {
+        outer.or(*inner)
+    }
+}
+
+#[test]
+fn lazy_apply_matches_brute_force() {
+    for len in 0..40 {
+        let mut sums = values(len, len as u64);
+        let mut mins = sums.clone();
+        let mut adding = LazySegmentTree::build(sums.iter().map(|&leaf| (leaf, 1)), SumLen, Adding);
+        let mut assigning = LazySegmentTree::build(mins.iter().copied(), Min, Assigning);
+
+        let ops = values(600, 11 + len as u64);
+        for op in ops.chunks_exact(3) {
+            let a = op[0] as usize * 3 % (len + 1);
+            let b = op[1] as usize * 7 % (len + 1);
+            let (start, end) = (a.min(b), a.max(b));
+            match op[2] % 4 {
+                0 => {
+                    adding.apply(start..end, op[2]);
+                    sums[start..end].iter_mut().for_each(|leaf| *leaf += op[2]);
+                }
+                1 => {
+                    assigning.apply(start..end, Some(op[2]));
+                    mins[start..end].fill(op[2]);
+                }
+                2 if start < len => {
+                    adding.update(start, (op[2], 1));
+                    sums[start] = op[2];
+                    assigning.update(start, op[2]);
+                    mins[start] = op[2];
+                }
+                _ => {
+                    let limit = op[2] * 3;
+                    let fits = |node: &(u64, u64)| node.0 <= limit;
+                    let expected = (start..=len)
+                        .rev()
+                        .find(|&end| fold(&Sum, &sums[start..end]) <= limit);
+                    assert_eq!(adding.max_right(start, fits), expected);
+                    let expected = (0..=end).find(|&start| fold(&Sum, &sums[start..end]) <= limit);
+                    assert_eq!(adding.min_left(end, fits), expected);
+                }
+            }
+
+            let sum = adding.query(start..end).map(|node| node.0);
+            assert_eq!(sum, Some(fold(&Sum, &sums[start..end])));
+            assert_eq!(
+                assigning.query(start..end),
+                Some(fold(&Min, &mins[start..end]))
+            );
+            assert_eq!(adding.all().0, fold(&Sum, &sums));
+            for (index, &leaf) in mins.iter().enumerate().step_by(5) {
+                assert_eq!(assigning.get(index), Some(&leaf));
+                assert_eq!(adding.get(index).map(|node| node.0), Some(sums[index]));
+            }
+        }
+        assert_eq!(adding.apply(0..len + 1, 1), None);
+        assert_eq!(assigning.query(0..len + 1), None);
+    }
+}
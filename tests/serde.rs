@@ -0,0 +1,26 @@
+use bypond::heap::IndexedHeap;
+
+#[test]
+fn indexed_heap_accepts_sparse_keys() {
+    let json = "[[18446744073709551615, 9], [0, 1]]";
+    let heap: IndexedHeap<2, i32> = serde_json::from_str(json).unwrap();
+    assert_eq!(heap.peek(), Some((usize::MAX, &9)));
+    assert_eq!(heap.get(0), Some(&1));
+}
+
+#[test]
+fn indexed_heap_rejects_duplicate_keys() {
+    let error = serde_json::from_str::<IndexedHeap<2, i32>>("[[4, 9], [4, 1]]")
+        .unwrap_err()
+        .to_string();
+    assert!(error.starts_with("duplicate key 4"));
+}
+
+#[test]
+fn indexed_heap_round_trips() {
+    let heap: IndexedHeap<2, i32> = [(3, 1), (0, 5), (7, 2)].into_iter().collect();
+    let json = serde_json::to_string(&heap).unwrap();
+    let heap: IndexedHeap<2, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(heap.peek(), Some((0, &5)));
+    assert_eq!(heap.get(7), Some(&2));
+}
@@ -0,0 +1,75 @@
+use bypond::SliceTree;
+use bypond::heap;
+
+fn values(len: usize, seed: u64) -> Vec<u32> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as u32 % 50
+        })
+        .collect()
+}
+
+fn check_sort<const N: usize>() {
+    for len in 0..80 {
+        let input = values(len, len as u64);
+        let mut expected = input.clone();
+        expected.sort();
+
+        let mut nodes = input.clone();
+        let tree: &mut SliceTree<N, u32> = nodes.as_mut_slice().into();
+        heap::heap_sort(tree);
+        assert_eq!(nodes, expected, "heap_sort, N = {N}, len = {len}");
+
+        let mut nodes = input.clone();
+        let tree: &mut SliceTree<N, u32> = nodes.as_mut_slice().into();
+        heap::heap_sort_by(tree, |a, b| b.cmp(a));
+        let mut descending = expected.clone();
+        descending.reverse();
+        assert_eq!(nodes, descending, "heap_sort_by, N = {N}, len = {len}");
+
+        let mut nodes: Vec<(u32, usize)> = input.iter().copied().zip(0..).collect();
+        let tree: &mut SliceTree<N, (u32, usize)> = nodes.as_mut_slice().into();
+        heap::heap_sort_by_key(tree, |&(value, _)| value);
+        let keys: Vec<_> = nodes.iter().map(|&(value, _)| value).collect();
+        assert_eq!(keys, expected, "heap_sort_by_key, N = {N}, len = {len}");
+
+        for k in [0, 1, len / 2, len, len + 3] {
+            let mut nodes = input.clone();
+            let tree: &mut SliceTree<N, u32> = nodes.as_mut_slice().into();
+            heap::partial_sort(tree, k);
+            let k = k.min(len);
+            assert_eq!(nodes[..k], expected[..k], "partial_sort, N = {N}, k = {k}");
+            let mut rest = nodes[k..].to_vec();
+            rest.sort();
+            assert_eq!(rest, expected[k..], "partial_sort, N = {N}, k = {k}");
+        }
+
+        for n in 0..=len {
+            let mut nodes = input.clone();
+            let tree: &mut SliceTree<N, u32> = nodes.as_mut_slice().into();
+            let nth = heap::select_nth_smallest(tree, n).copied();
+            assert_eq!(nth, expected.get(n).copied(), "select, N = {N}, n = {n}");
+
+            let mut nodes = input.clone();
+            let tree: &mut SliceTree<N, u32> = nodes.as_mut_slice().into();
+            let nth = heap::select_nth_smallest_by(tree, n, |a, b| b.cmp(a)).copied();
+            assert_eq!(
+                nth,
+                descending.get(n).copied(),
+                "select_by, N = {N}, n = {n}"
+            );
+        }
+    }
+}
+
+#[test]
+fn sorts_match_slice_sort() {
+    check_sort::<1>();
+    check_sort::<2>();
+    check_sort::<3>();
+    check_sort::<4>();
+}
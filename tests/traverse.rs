@@ -0,0 +1,314 @@
+use bypond::index::traverse::{InOrder, PostOrder, PreOrder};
+use bypond::{CompleteBinaryTree, CompleteTree, Index};
+
+fn children<const N: usize>(index: usize, len: usize) -> impl Iterator<Item = usize> {
+    (1..=N)
+        .map(move |n| N * index + n)
+        .filter(move |&child| child < len)
+}
+
+fn pre_order<const N: usize>(index: usize, len: usize, out: &mut Vec<usize>) {
+    if index < len {
+        out.push(index);
+        children::<N>(index, len).for_each(|child| pre_order::<N>(child, len, out));
+    }
+}
+
+fn post_order<const N: usize>(index: usize, len: usize, out: &mut Vec<usize>) {
+    if index < len {
+        children::<N>(index, len).for_each(|child| post_order::<N>(child, len, out));
+        out.push(index);
+    }
+}
+
+fn in_order(index: usize, len: usize, out: &mut Vec<usize>) {
+    if index < len {
+        in_order(2 * index + 1, len, out);
+        out.push(index);
+        in_order(2 * index + 2, len, out);
+    }
+}
+
+fn reference(visit: impl Fn(usize, usize, &mut Vec<usize>), len: usize) -> Vec<usize> {
+    let mut out = Vec::new();
+    visit(0, len, &mut out);
+    out
+}
+
+fn check_orders<const N: usize>() {
+    // Covers the empty tree, a lone root, and both full and partial last levels.
+    for len in 0..90 {
+        let nodes: Vec<usize> = (0..len).collect();
+        let tree = nodes.as_slice();
+
+        let expected = reference(pre_order::<N>, len);
+        let indices: Vec<_> = PreOrder::<N>::new(len).map(Index::to_flattened).collect();
+        let slice: Vec<_> = CompleteTree::<N>::traverse_pre_order(tree)
+            .copied()
+            .collect();
+        assert_eq!(indices, expected, "pre-order, N = {N}, len = {len}");
+        assert_eq!(slice, expected, "pre-order, N = {N}, len = {len}");
+
+        let expected = reference(post_order::<N>, len);
+        let indices: Vec<_> = PostOrder::<N>::new(len).map(Index::to_flattened).collect();
+        let slice: Vec<_> = CompleteTree::<N>::traverse_post_order(tree)
+            .copied()
+            .collect();
+        assert_eq!(indices, expected, "post-order, N = {N}, len = {len}");
+        assert_eq!(slice, expected, "post-order, N = {N}, len = {len}");
+    }
+}
+
+#[test]
+fn orders_match_recursive_reference() {
+    check_orders::<1>();
+    check_orders::<2>();
+    check_orders::<3>();
+    check_orders::<4>();
+
+    for len in 0..90 {
+        let nodes: Vec<usize> = (0..len).collect();
+        let expected = reference(in_order, len);
+        let indices: Vec<_> = InOrder::new(len).map(Index::to_flattened).collect();
+        let slice: Vec<_> = nodes.as_slice().traverse_in_order().copied().collect();
+        assert_eq!(indices, expected, "in-order, len = {len}");
+        assert_eq!(slice, expected, "in-order, len = {len}");
+    }
+}
+
+fn check_double_ended<I>(mut iter: I, expected: &[usize], pattern: u32)
+where
+    I: DoubleEndedIterator<Item = usize> + ExactSizeIterator,
+{
+    let mut front = Vec::new();
+    let mut back = Vec::new();
+    for step in 0..expected.len() {
+        assert_eq!(iter.len(), expected.len() - step);
+        if pattern >> (step % 32) & 1 == 0 {
+            front.push(iter.next().unwrap());
+        } else {
+            back.push(iter.next_back().unwrap());
+        }
+    }
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    back.reverse();
+    front.extend(back);
+    assert_eq!(front, expected);
+}
+
+fn check_ends<const N: usize>() {
+    // Alternating ends meet in the middle; the other patterns drain one end
+    // first or switch at irregular steps.
+    let patterns = [
+        0,
+        u32::MAX,
+        0x5555_5555,
+        0xaaaa_aaaa,
+        0x0f0f_0f0f,
+        0x1234_5678,
+    ];
+    for len in 0..60 {
+        let nodes: Vec<usize> = (0..len).collect();
+        let tree = nodes.as_slice();
+        let pre = reference(pre_order::<N>, len);
+        let post = reference(post_order::<N>, len);
+        for pattern in patterns {
+            let indices = PreOrder::<N>::new(len).map(Index::to_flattened);
+            check_double_ended(indices, &pre, pattern);
+            let slice = CompleteTree::<N>::traverse_pre_order(tree).copied();
+            check_double_ended(slice, &pre, pattern);
+
+            let indices = PostOrder::<N>::new(len).map(Index::to_flattened);
+            check_double_ended(indices, &post, pattern);
+            let slice = CompleteTree::<N>::traverse_post_order(tree).copied();
+            check_double_ended(slice, &post, pattern);
+        }
+    }
+}
+
+#[test]
+fn ends_meet_with_exact_len() {
+    check_ends::<1>();
+    check_ends::<2>();
+    check_ends::<3>();
+    check_ends::<4>();
+
+    let patterns = [
+        0,
+        u32::MAX,
+        0x5555_5555,
+        0xaaaa_aaaa,
+        0x0f0f_0f0f,
+        0x1234_5678,
+    ];
+    for len in 0..60 {
+        let nodes: Vec<usize> = (0..len).collect();
+        let expected = reference(in_order, len);
+        for pattern in patterns {
+            let indices = InOrder::new(len).map(Index::to_flattened);
+            check_double_ended(indices, &expected, pattern);
+            let slice = nodes.as_slice().traverse_in_order().copied();
+            check_double_ended(slice, &expected, pattern);
+        }
+    }
+}
+
+fn check_max_depth<const N: usize, I>(new: impl Fn(Index<N>, usize) -> I)
+where
+    I: DoubleEndedIterator<Item = Index<N>> + ExactSizeIterator,
+    I: MaxDepth,
+{
+    for tree_len in 0..40 {
+        for root in 0..tree_len.max(1) {
+            let root = Index::<N>::from_flattened(root);
+            let full: Vec<_> = new(root, tree_len).collect();
+            for front in 0..=full.len() {
+                for back in 0..=full.len() - front {
+                    for depth in 0..5 {
+                        let mut iter = new(root, tree_len);
+                        iter.by_ref().take(front).for_each(drop);
+                        iter.by_ref().rev().take(back).for_each(drop);
+                        let iter = iter.max_depth(depth);
+
+                        let expected: Vec<_> = full[front..full.len() - back]
+                            .iter()
+                            .copied()
+                            .filter(|index| index.depth() - root.depth() <= depth)
+                            .collect();
+                        assert_eq!(iter.len(), expected.len());
+                        assert_eq!(iter.clone().collect::<Vec<_>>(), expected);
+                        let mut reversed: Vec<_> = iter.rev().collect();
+                        reversed.reverse();
+                        assert_eq!(reversed, expected);
+                    }
+                }
+            }
+        }
+    }
+}
+
+trait MaxDepth: Clone {
+    fn max_depth(self, depth: usize) -> Self;
+}
+
+impl<const N: usize> MaxDepth for PreOrder<N> {
+    fn max_depth(self, depth: usize) -> Self {
+        PreOrder::max_depth(self, depth)
+    }
+}
+
+impl<const N: usize> MaxDepth for PostOrder<N> {
+    fn max_depth(self, depth: usize) -> Self {
+        PostOrder::max_depth(self, depth)
+    }
+}
+
+impl MaxDepth for InOrder {
+    fn max_depth(self, depth: usize) -> Self {
+        InOrder::max_depth(self, depth)
+    }
+}
+
+#[test]
+fn max_depth_after_partial_consumption() {
+    check_max_depth::<1, _>(PreOrder::new_from);
+    check_max_depth::<2, _>(PreOrder::new_from);
+    check_max_depth::<3, _>(PreOrder::new_from);
+    check_max_depth::<1, _>(PostOrder::new_from);
+    check_max_depth::<2, _>(PostOrder::new_from);
+    check_max_depth::<3, _>(PostOrder::new_from);
+    check_max_depth::<2, _>(InOrder::new_from);
+}
+
+#[test]
+fn max_depth_mut_never_repeats_nodes() {
+    let mut nodes: Vec<usize> = (0..31).collect();
+    let tree = nodes.as_mut_slice();
+
+    let mut iter = CompleteTree::<2>::traverse_pre_order_mut(tree);
+    let first = iter.next().unwrap();
+    let second = iter.next().unwrap();
+    let rest: Vec<_> = iter.max_depth(2).collect();
+    assert_eq!((*first, *second), (0, 1));
+    assert_eq!(
+        rest.into_iter().map(|node| *node).collect::<Vec<_>>(),
+        [3, 4, 2, 5, 6]
+    );
+
+    let mut iter = CompleteTree::<2>::traverse_post_order_mut(tree);
+    let first = iter.next().unwrap();
+    let rest: Vec<_> = iter.max_depth(1).collect();
+    assert_eq!(*first, 15);
+    assert_eq!(
+        rest.into_iter().map(|node| *node).collect::<Vec<_>>(),
+        [1, 2, 0]
+    );
+
+    let mut iter = tree.traverse_in_order_mut();
+    let last = iter.next_back().unwrap();
+    let rest: Vec<_> = iter.max_depth(1).collect();
+    assert_eq!(*last, 30);
+    assert_eq!(
+        rest.into_iter().map(|node| *node).collect::<Vec<_>>(),
+        [1, 0, 2]
+    );
+}
+
+fn check_dyn_max_depth<const N: usize>() {
+    use bypond::DynIndex;
+    use bypond::dyn_index::traverse as dyn_traverse;
+    use bypond::index::traverse::LevelOrder;
+
+    for tree_len in 0..40 {
+        for depth in 0..5 {
+            let expected: Vec<_> = LevelOrder::<N>::new(tree_len)
+                .max_depth(depth)
+                .map(Index::to_flattened)
+                .collect();
+            let found: Vec<_> = dyn_traverse::LevelOrder::new(N, tree_len)
+                .unwrap()
+                .max_depth(depth)
+                .map(DynIndex::to_flattened)
+                .collect();
+            assert_eq!(found, expected);
+        }
+
+        for root in 0..tree_len.max(1) {
+            let index = Index::<N>::from_flattened(root);
+            let dyn_index = DynIndex::from(index);
+            for front in 0..3 {
+                for depth in 0..5 {
+                    let mut expected = PreOrder::new_from(index, tree_len);
+                    let mut found = dyn_traverse::PreOrder::new_from(dyn_index, tree_len);
+                    expected.by_ref().take(front).for_each(drop);
+                    found.by_ref().take(front).for_each(drop);
+                    let expected: Vec<_> =
+                        expected.max_depth(depth).map(Index::to_flattened).collect();
+                    let found: Vec<_> =
+                        found.max_depth(depth).map(DynIndex::to_flattened).collect();
+                    assert_eq!(found, expected);
+
+                    let mut expected = PostOrder::new_from(index, tree_len);
+                    let mut found = dyn_traverse::PostOrder::new_from(dyn_index, tree_len);
+                    expected.by_ref().rev().take(front).for_each(drop);
+                    found.by_ref().rev().take(front).for_each(drop);
+                    let expected: Vec<_> =
+                        expected.max_depth(depth).map(Index::to_flattened).collect();
+                    let found: Vec<_> =
+                        found.max_depth(depth).map(DynIndex::to_flattened).collect();
+                    assert_eq!(found, expected);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn dyn_max_depth_matches_const() {
+    check_dyn_max_depth::<1>();
+    check_dyn_max_depth::<2>();
+    check_dyn_max_depth::<3>();
+}